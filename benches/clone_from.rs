@@ -0,0 +1,52 @@
+#![allow(dead_code)]
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use derive_bounded::Clone;
+
+trait Associate {
+    type A: Clone;
+    type B: Clone;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u64;
+    type B = String;
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A, T::B)]
+struct Inner<T: Associate> {
+    items: Vec<T::A>,
+    name: T::B,
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A, T::B)]
+struct Outer<T: Associate> {
+    inner: Inner<T>,
+    tags: Vec<T::B>,
+}
+
+fn sample() -> Outer<Holder> {
+    Outer {
+        inner: Inner {
+            items: (0..1024).collect(),
+            name: "inner".repeat(16),
+        },
+        tags: (0..16).map(|i| format!("tag-{i}")).collect(),
+    }
+}
+
+fn clone_vs_clone_from(c: &mut Criterion) {
+    let source = sample();
+    let mut dest = sample();
+
+    c.bench_function("clone", |b| b.iter(|| black_box(&source).clone()));
+    c.bench_function("clone_from", |b| {
+        b.iter(|| dest.clone_from(black_box(&source)))
+    });
+}
+
+criterion_group!(benches, clone_vs_clone_from);
+criterion_main!(benches);
@@ -0,0 +1,32 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use syn::DeriveInput;
+
+/// Struct with `len` fields sharing the same associated type
+fn many_fields(len: usize) -> DeriveInput {
+    let fields = (0..len).map(|i| format!("f{i}: T::A,")).collect::<String>();
+
+    syn::parse_str(&format!(
+        "#[bounded_to(T::A)] struct Many<T: Associate> {{ {fields} }}"
+    ))
+    .unwrap()
+}
+
+fn expand_many_fields(c: &mut Criterion) {
+    let mut group = c.benchmark_group("expand");
+
+    for len in [10, 100, 1000] {
+        let input = many_fields(len);
+        group.throughput(Throughput::Elements(len as u64));
+        group.bench_with_input(BenchmarkId::new("clone", len), &input, |b, input| {
+            b.iter(|| derive_bounded_core::derive_clone(black_box(input.clone())))
+        });
+        group.bench_with_input(BenchmarkId::new("hash_eq", len), &input, |b, input| {
+            b.iter(|| derive_bounded_core::derive_hash_eq(black_box(input.clone())))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, expand_many_fields);
+criterion_main!(benches);
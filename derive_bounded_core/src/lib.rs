@@ -0,0 +1,3314 @@
+//! Expansion of the [derive_bounded](https://docs.rs/derive_bounded) macros
+//!
+//! The functions take the parsed item and return the generated impls, so they can be used
+//! outside of a procedural macro, e.g. to generate code from a build script.
+
+use std::collections::HashMap;
+use std::ops::Not;
+
+use darling::ast::Style;
+use proc_macro2::{Group, Spacing, Span, TokenStream as TokenStream2, TokenTree};
+use quote::{format_ident, quote, ToTokens};
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::punctuated::Punctuated;
+use syn::spanned::Spanned;
+use syn::token::{self, Comma};
+use syn::visit::Visit;
+use syn::visit_mut::VisitMut;
+use syn::{
+    parenthesized, parse_quote, DeriveInput, Fields, Generics, Ident, PredicateType, TypePath,
+};
+
+use darling::usage::{
+    CollectTypeParams, GenericsExt, IdentRefSet, IdentSet, Purpose, UsesTypeParams,
+};
+use darling::util::{Flag, PathList};
+use darling::{FromDeriveInput, FromMeta};
+
+#[derive(std::fmt::Debug, FromDeriveInput)]
+#[darling(
+    attributes(bounded),
+    forward_attrs(bounded_to, debug, display, hash, partial_eq, repr)
+)]
+struct BoundedDerive {
+    ident: syn::Ident,
+    generics: syn::Generics,
+    data: darling::ast::Data<syn::Variant, syn::Field>,
+    attrs: Vec<syn::Attribute>,
+    /// Types bounded only in the `Eq` impl, in place of the `bounded_to` ones
+    eq_bound: Option<BoundedTypes>,
+    /// Additional marker types, implementing the traits regardless of their parameters
+    markers: Option<PathList>,
+    /// Fields compared by `PartialOrd` and `Ord`, in comparison order
+    partial_ord_only_fields: Option<PathList>,
+    /// Pretty print with `Debug` even without the alternate flag
+    debug_pretty: Flag,
+    /// Print tuple structs with `Debug` as structs, naming the fields by their index
+    debug_tuple_as_struct: Flag,
+    /// Clone by copying `*self` if every field is trivially `Copy`
+    clone_copy: Flag,
+    /// Bounds on `Self` added to every impl, e.g. `"Default + Send"`
+    where_self: Option<String>,
+    /// Add an inherent `boxed_clone` method next to `Clone`
+    boxed_clone: Flag,
+    /// Bound only the `bounded_to` types, without inferring the leftover type parameters
+    strict: Flag,
+    /// Paths replacing the crate roots of the generated absolute paths, e.g. `serde = "fw::serde"`
+    crate_paths: Option<HashMap<String, syn::Path>>,
+}
+
+impl BoundedDerive {
+    fn markers(&self) -> &[syn::Path] {
+        self.markers.as_ref().map_or(&[], |markers| markers)
+    }
+}
+
+/// List of types to bound, as `name(T::A, T::B)` or `name = "T::A, <T as Trait>::B"`
+#[derive(std::fmt::Debug)]
+struct BoundedTypes(Vec<syn::Type>);
+
+impl FromMeta for BoundedTypes {
+    fn from_list(items: &[syn::NestedMeta]) -> darling::Result<Self> {
+        items
+            .iter()
+            .map(|item| match item {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => Ok(TypePath {
+                    qself: None,
+                    path: path.clone(),
+                }
+                .into()),
+                syn::NestedMeta::Lit(lit) => syn::Type::from_value(lit),
+                _ => Err(darling::Error::unsupported_format("expected a type").with_span(item)),
+            })
+            .collect::<darling::Result<_>>()
+            .map(BoundedTypes)
+    }
+
+    fn from_value(value: &syn::Lit) -> darling::Result<Self> {
+        Punctuated::<syn::Type, Comma>::from_value(value)
+            .map(|types| BoundedTypes(types.into_iter().collect()))
+    }
+}
+
+/// Item of `bounded_to`, a type bounded by the derived trait or an explicit predicate
+enum BoundedItem {
+    Type(syn::Type),
+    /// `T::B: Send + Sync`, added as it is
+    Predicate(PredicateType),
+}
+
+impl Parse for BoundedItem {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let bounded_ty = input.parse()?;
+        if !input.peek(syn::Token![:]) {
+            return Ok(BoundedItem::Type(bounded_ty));
+        }
+
+        Ok(BoundedItem::Predicate(PredicateType {
+            lifetimes: None,
+            bounded_ty,
+            colon_token: input.parse()?,
+            bounds: Punctuated::parse_separated_nonempty(input)?,
+        }))
+    }
+}
+
+impl ToTokens for BoundedItem {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        match self {
+            BoundedItem::Type(ty) => ty.to_tokens(tokens),
+            BoundedItem::Predicate(predicate) => predicate.to_tokens(tokens),
+        }
+    }
+}
+
+struct BoundedAttr {
+    _paren_token: token::Paren,
+    items: Punctuated<BoundedItem, Comma>,
+}
+
+impl Parse for BoundedAttr {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let content;
+        let parent_token = parenthesized!(content in input);
+        let mut items = Punctuated::new();
+        while !content.is_empty() {
+            // `types(T::A, T::B)` and the group `(T::A, T::B)` stand for `T::A, T::B`, instead
+            // of a `Fn`-like path type and a tuple
+            let listed = content.peek(Ident)
+                && content.peek2(token::Paren)
+                && content.fork().parse::<Ident>()? == "types";
+            if listed || content.peek(token::Paren) {
+                if listed {
+                    content.parse::<Ident>()?;
+                }
+                let types;
+                let paren = parenthesized!(types in content);
+                if types.is_empty() {
+                    return Err(syn::Error::new(paren.span, "expected at least one type"));
+                }
+                items.extend(types.parse_terminated::<_, Comma>(BoundedItem::parse)?);
+            } else {
+                items.push(content.parse()?);
+            }
+
+            if !content.is_empty() {
+                content.parse::<Comma>()?;
+            }
+        }
+
+        Ok(BoundedAttr {
+            _paren_token: parent_token,
+            items,
+        })
+    }
+}
+
+/// Arguments of the umbrella attribute, as `derive(Clone, Debug), to(T::A, T::B)`
+struct UmbrellaArgs {
+    derives: Punctuated<syn::Path, Comma>,
+    types: Punctuated<BoundedItem, Comma>,
+}
+
+impl Parse for UmbrellaArgs {
+    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
+        let mut derives = Punctuated::new();
+        let mut types = Punctuated::new();
+
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            if ident == "derive" {
+                derives.extend(content.parse_terminated::<_, Comma>(syn::Path::parse)?);
+            } else if ident == "to" {
+                types.extend(content.parse_terminated::<_, Comma>(BoundedItem::parse)?);
+            } else {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    "expected `derive(...)` or `to(...)`",
+                ));
+            }
+
+            if !input.is_empty() {
+                input.parse::<Comma>()?;
+            }
+        }
+
+        Ok(UmbrellaArgs { derives, types })
+    }
+}
+
+/// Field options accepted by `#[clone(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct CloneAttr {
+    bound: Option<Vec<syn::WherePredicate>>,
+    with: Option<syn::Path>,
+    /// Expression evaluating to the clone, it may use `self`
+    via: Option<syn::Expr>,
+}
+
+/// Field options accepted by `#[default(...)]`, a literal value can be set as `#[default = 42]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct DefaultAttr {
+    value: Option<syn::Expr>,
+    with: Option<syn::Path>,
+}
+
+impl DefaultAttr {
+    fn parse(field: &syn::Field) -> darling::Result<Self> {
+        let mut items = Vec::new();
+        let mut literal = None;
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("default"))
+        {
+            match attr.parse_meta()? {
+                syn::Meta::List(list) => items.extend(list.nested),
+                syn::Meta::NameValue(value) if literal.is_none() => literal = Some(value.lit),
+                syn::Meta::NameValue(value) => {
+                    return Err(darling::Error::duplicate_field("default").with_span(&value))
+                }
+                syn::Meta::Path(path) => {
+                    return Err(darling::Error::unsupported_format(
+                        "expected #[default = literal], #[default(value = \"...\")] or #[default(with = \"...\")]",
+                    )
+                    .with_span(&path))
+                }
+            }
+        }
+
+        let mut attr = if items.is_empty() {
+            Self::default()
+        } else {
+            Self::from_list(&items)?
+        };
+
+        if let Some(lit) = literal {
+            if attr.value.is_some() || attr.with.is_some() {
+                return Err(darling::Error::custom(
+                    "#[default = literal] cannot be combined with #[default(...)]",
+                )
+                .with_span(&lit));
+            }
+            attr.value = Some(syn::Expr::Lit(syn::ExprLit {
+                attrs: Vec::new(),
+                lit,
+            }));
+        }
+
+        if let (Some(_), Some(with)) = (&attr.value, &attr.with) {
+            return Err(
+                darling::Error::custom("`value` and `with` cannot be combined").with_span(with),
+            );
+        }
+
+        Ok(attr)
+    }
+}
+
+/// Initialize a field with its `#[default(...)]` value or `Default::default()`
+fn default_field(field: &syn::Field) -> TokenStream2 {
+    let attr = DefaultAttr::parse(field).unwrap_or_default();
+    let ty = &field.ty;
+
+    match (attr.value, attr.with) {
+        (Some(value), _) => quote! { #value },
+        (None, Some(with)) => quote! { #with() },
+        (None, None) => quote! { <#ty as ::std::default::Default>::default() },
+    }
+}
+
+/// Container options accepted by `#[debug(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct DebugAttr {
+    with_discriminant: bool,
+    /// Predicates of the `Debug` impl, in place of the inferred and `bounded_to` ones
+    bound: Option<String>,
+}
+
+/// Container options accepted by `#[display(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct DisplayAttr {
+    /// Separator written between the fields
+    join: Option<String>,
+}
+
+/// Field options accepted by `#[debug(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct DebugFieldAttr {
+    skip_if: Option<syn::Path>,
+    max_len: Option<usize>,
+}
+
+/// Container options accepted by `#[partial_eq(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct PartialEqAttr {
+    /// Types a single-field struct is also comparable with, through its field
+    with: Option<BoundedTypes>,
+    /// Generate `approx_eq`, comparing the float fields within a tolerance
+    approx: bool,
+}
+
+/// Field options accepted by `#[partial_eq(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct PartialEqFieldAttr {
+    unordered: bool,
+    total_float: bool,
+}
+
+/// Container options accepted by `#[hash(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct HashAttr {
+    named: bool,
+    /// Hash the variant names instead of their declaration index
+    variant_name: bool,
+}
+
+/// Field options accepted by `#[hash(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct HashFieldAttr {
+    unordered: bool,
+    with: Option<syn::Path>,
+}
+
+/// Field options accepted by `#[ord(...)]`
+#[derive(std::default::Default, FromMeta)]
+#[darling(default)]
+struct OrdAttr {
+    with: Option<syn::Path>,
+}
+
+/// Collect every `#[name(...)]` attribute and parse their merged contents
+fn parse_attrs<T: FromMeta + Default>(attrs: &[syn::Attribute], name: &str) -> darling::Result<T> {
+    let mut items = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident(name)) {
+        match attr.parse_meta()? {
+            syn::Meta::List(list) => items.extend(list.nested),
+            meta => {
+                return Err(
+                    darling::Error::unsupported_format(&format!("expected {name}(...)"))
+                        .with_span(&meta),
+                )
+            }
+        }
+    }
+
+    if items.is_empty() {
+        Ok(T::default())
+    } else {
+        T::from_list(&items)
+    }
+}
+
+/// Split the fields between the ones with an explicit bound and the ones relying on inference
+fn field_bounds<'a>(
+    field_bound: fn(field: &syn::Field) -> darling::Result<Option<Vec<syn::WherePredicate>>>,
+    fields: impl Iterator<Item = &'a syn::Field>,
+) -> darling::Result<(Vec<&'a syn::Field>, Vec<syn::WherePredicate>)> {
+    let mut errors = darling::Error::accumulator();
+    let mut inferred = Vec::new();
+    let mut predicates = Vec::new();
+
+    for field in fields {
+        match errors.handle(field_bound(field)) {
+            Some(Some(bound)) => predicates.extend(bound),
+            Some(None) => inferred.push(field),
+            None => {}
+        }
+    }
+
+    errors.finish_with((inferred, predicates))
+}
+
+fn normalize_generics<'a>(
+    bound: TokenStream2,
+    generics: &mut Generics,
+    types: impl Iterator<Item = &'a syn::Type>,
+) {
+    let bounds = generics
+        .type_params_mut()
+        .filter_map(|par| {
+            par.bounds.is_empty().not().then_some({
+                let bounds = std::mem::take(&mut par.bounds);
+
+                let path: syn::Path = par.ident.clone().into();
+                let path = TypePath { qself: None, path };
+
+                PredicateType {
+                    lifetimes: None,
+                    bounded_ty: path.into(),
+                    colon_token: Default::default(),
+                    bounds,
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+
+    let where_clause = generics.make_where_clause();
+    for bound in bounds {
+        where_clause.predicates.push(bound.into());
+    }
+
+    for ty in types {
+        let pred: syn::WherePredicate = parse_quote! { #ty: #bound };
+
+        where_clause.predicates.push(pred);
+    }
+}
+
+/// Marker types implementing the derivable traits regardless of their parameters
+const MARKERS: &[&str] = &["PhantomData", "PhantomPinned"];
+
+/// Reference counted pointers, strong or weak, cloning them does not clone the pointee
+const SHARED: &[&str] = &["Arc", "Rc", "Weak"];
+
+/// Primitive types, all of them are `Copy`
+const PRIMITIVES: &[&str] = &[
+    "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32",
+    "u64", "u128", "usize",
+];
+
+fn last_segment(ty: &syn::Type) -> Option<&syn::PathSegment> {
+    match ty {
+        syn::Type::Path(TypePath { qself: None, path }) => path.segments.last(),
+        _ => None,
+    }
+}
+
+fn is_shared(ty: &syn::Type) -> bool {
+    last_segment(ty).is_some_and(|last| SHARED.iter().any(|shared| last.ident == shared))
+}
+
+fn is_marker(ty: &syn::Type, markers: &[syn::Path]) -> bool {
+    last_segment(ty).is_some_and(|last| {
+        MARKERS.iter().any(|marker| last.ident == marker)
+            || markers
+                .iter()
+                .filter_map(|marker| marker.segments.last())
+                .any(|marker| marker.ident == last.ident)
+    })
+}
+
+/// Associated types fixed by bounds such as `T: Trait<A = U>`, as the `T::A` tokens and `U`
+fn associated_equalities(generics: &Generics) -> Vec<(String, syn::Type)> {
+    let params = generics.type_params().map(|param| {
+        let ty: syn::Type = syn::Type::from(TypePath {
+            qself: None,
+            path: param.ident.clone().into(),
+        });
+        (ty, &param.bounds)
+    });
+    let predicates = generics
+        .where_clause
+        .iter()
+        .flat_map(|clause| clause.predicates.iter())
+        .filter_map(|predicate| match predicate {
+            syn::WherePredicate::Type(PredicateType {
+                bounded_ty, bounds, ..
+            }) => Some((bounded_ty.clone(), bounds)),
+            _ => None,
+        });
+
+    let mut equalities = Vec::new();
+    for (ty, bounds) in params.chain(predicates) {
+        let bindings = bounds
+            .iter()
+            .filter_map(|bound| match bound {
+                syn::TypeParamBound::Trait(bound) => bound.path.segments.last(),
+                _ => None,
+            })
+            .filter_map(|segment| match segment.arguments {
+                syn::PathArguments::AngleBracketed(ref args) => Some(args.args.iter()),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|arg| match arg {
+                syn::GenericArgument::Binding(binding) => Some(binding),
+                _ => None,
+            });
+
+        for binding in bindings {
+            let ident = &binding.ident;
+            let path = quote! { #ty::#ident }.to_string();
+            equalities.push((path, binding.ty.clone()));
+        }
+    }
+
+    equalities
+}
+
+/// Replace the associated types fixed by a bound with the types they are equal to
+struct ResolveEqualities<'a>(&'a [(String, syn::Type)]);
+
+impl VisitMut for ResolveEqualities<'_> {
+    fn visit_type_mut(&mut self, ty: &mut syn::Type) {
+        if let syn::Type::Path(TypePath { qself: None, path }) = ty {
+            let path = path.to_token_stream().to_string();
+            if let Some((_, equal)) = self.0.iter().find(|(assoc, _)| *assoc == path) {
+                *ty = equal.clone();
+                return;
+            }
+        }
+
+        syn::visit_mut::visit_type_mut(self, ty);
+    }
+}
+
+/// Collect the type parameters used by a field type, ignoring the ones only used by markers,
+/// behind raw or function pointers or by the `unconditional` types, as those implement the
+/// traits anyway
+fn field_type_params<'a>(
+    ty: &syn::Type,
+    markers: &[syn::Path],
+    unconditional: fn(ty: &syn::Type) -> bool,
+    type_params: &'a IdentSet,
+) -> IdentRefSet<'a> {
+    let options = Purpose::BoundImpl.into();
+
+    if is_marker(ty, markers) || unconditional(ty) {
+        return IdentRefSet::default();
+    }
+
+    match ty {
+        syn::Type::Path(TypePath { qself: None, path }) if path.leading_colon.is_none() => {
+            let mut used = path.segments[0]
+                .ident
+                .uses_type_params(&options, type_params);
+
+            for segment in path.segments.iter() {
+                match segment.arguments {
+                    syn::PathArguments::AngleBracketed(ref args) => {
+                        for arg in args.args.iter() {
+                            match arg {
+                                syn::GenericArgument::Type(ty) => used.extend(field_type_params(
+                                    ty,
+                                    markers,
+                                    unconditional,
+                                    type_params,
+                                )),
+                                arg => used.extend(arg.uses_type_params(&options, type_params)),
+                            }
+                        }
+                    }
+                    ref arguments => used.extend(arguments.uses_type_params(&options, type_params)),
+                }
+            }
+
+            used
+        }
+        syn::Type::Array(array) => {
+            field_type_params(&array.elem, markers, unconditional, type_params)
+        }
+        syn::Type::Group(group) => {
+            field_type_params(&group.elem, markers, unconditional, type_params)
+        }
+        syn::Type::Paren(paren) => {
+            field_type_params(&paren.elem, markers, unconditional, type_params)
+        }
+        syn::Type::BareFn(_) | syn::Type::Ptr(_) => IdentRefSet::default(),
+        syn::Type::Reference(reference) => {
+            field_type_params(&reference.elem, markers, unconditional, type_params)
+        }
+        syn::Type::Slice(slice) => {
+            field_type_params(&slice.elem, markers, unconditional, type_params)
+        }
+        syn::Type::Tuple(tuple) => {
+            tuple
+                .elems
+                .iter()
+                .fold(IdentRefSet::default(), |mut used, ty| {
+                    used.extend(field_type_params(ty, markers, unconditional, type_params));
+                    used
+                })
+        }
+        ty => ty.uses_type_params(&options, type_params),
+    }
+}
+
+/// Check if `ty` is `Self` or the derived type, whatever its generic arguments
+fn is_derived_type(input: &BoundedDerive, ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => {
+            path.segments.len() == 1
+                && (path.segments[0].ident == "Self" || path.segments[0].ident == input.ident)
+        }
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. }) => is_derived_type(input, elem),
+        _ => false,
+    }
+}
+
+/// Check if `Self` appears anywhere in the tokens
+fn mentions_self(tokens: TokenStream2) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => ident == "Self",
+        proc_macro2::TokenTree::Group(group) => mentions_self(group.stream()),
+        _ => false,
+    })
+}
+
+fn mentions_type_param(tokens: TokenStream2, type_params: &IdentSet) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(ident) => type_params.contains(&ident),
+        proc_macro2::TokenTree::Group(group) => mentions_type_param(group.stream(), type_params),
+        _ => false,
+    })
+}
+
+/// Array lengths depending on type parameters, as `[u8; T::LEN]`, that cannot be bounded
+struct ParamLengths<'ast, 'a> {
+    type_params: &'a IdentSet,
+    found: Vec<&'ast syn::Expr>,
+}
+
+impl<'ast> Visit<'ast> for ParamLengths<'ast, '_> {
+    fn visit_type_array(&mut self, array: &'ast syn::TypeArray) {
+        if mentions_type_param(array.len.to_token_stream(), self.type_params) {
+            self.found.push(&array.len);
+        }
+
+        syn::visit::visit_type_array(self, array);
+    }
+}
+
+/// Emit the where clause predicates in a stable order, regardless of how they were collected
+fn sort_predicates(generics: &mut Generics) {
+    if let Some(where_clause) = generics.where_clause.as_mut() {
+        let mut predicates = std::mem::take(&mut where_clause.predicates)
+            .into_iter()
+            .map(|pred| (pred.to_token_stream().to_string(), pred))
+            .collect::<Vec<_>>();
+
+        predicates.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        where_clause.predicates = predicates.into_iter().map(|(_, pred)| pred).collect();
+    }
+}
+
+struct Generator {
+    named_body: fn(input: &BoundedDerive, generics: Generics, inner: TokenStream2) -> TokenStream2,
+    unnamed_body:
+        fn(input: &BoundedDerive, generics: Generics, inner: TokenStream2) -> TokenStream2,
+    enum_body: fn(input: &BoundedDerive, generics: Generics, inner: TokenStream2) -> TokenStream2,
+
+    named_field: fn(input: &BoundedDerive, ident: &Ident, field: &syn::Field) -> TokenStream2,
+    unnamed_field: fn(input: &BoundedDerive, index: syn::Index, field: &syn::Field) -> TokenStream2,
+    enum_fields: fn(variant: &syn::Variant) -> TokenStream2,
+
+    /// Explicit predicates replacing the inferred bounds for a field
+    field_bound: fn(field: &syn::Field) -> darling::Result<Option<Vec<syn::WherePredicate>>>,
+    /// Types implementing the trait whatever their parameters are
+    unconditional: fn(ty: &syn::Type) -> bool,
+    /// Trait-specific types replacing the `bounded_to` ones
+    scoped_types: fn(input: &BoundedDerive) -> Option<&BoundedTypes>,
+    /// Trait-specific predicates replacing both the inferred and the `bounded_to` bounds
+    scoped_predicates:
+        fn(input: &BoundedDerive) -> darling::Result<Option<Vec<syn::WherePredicate>>>,
+    /// Enum variants taking part in the impl
+    select_variants: fn(variants: &[syn::Variant]) -> darling::Result<Vec<&syn::Variant>>,
+    /// Struct fields taking part in the impl, in order
+    select_fields: fn(input: &BoundedDerive) -> darling::Result<Vec<&syn::Field>>,
+}
+
+fn variant_fields(prefix: &Ident, fields: &syn::Fields) -> Vec<Ident> {
+    match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .map(|f| {
+                let field = f.ident.as_ref().unwrap();
+                format_ident!("{prefix}_{field}")
+            })
+            .collect(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(i, _)| format_ident!("{prefix}_{i}"))
+            .collect(),
+        Fields::Unit => Vec::new(),
+    }
+}
+
+fn unpack_struct(fields: &syn::Fields, bindings: &[Ident]) -> TokenStream2 {
+    match fields {
+        Fields::Named(named) => {
+            let args = named
+                .named
+                .iter()
+                .map(|f| f.ident.as_ref().unwrap())
+                .zip(bindings)
+                .map(|(v, f)| quote! { #v: #f });
+            quote! {
+                { #(#args, )* }
+            }
+        }
+        Fields::Unnamed(_) => {
+            quote! {
+                ( #(#bindings, )* )
+            }
+        }
+        Fields::Unit => TokenStream2::new(),
+    }
+}
+
+/// Parse the input of the `name` derive, which supports only structs and enums
+fn parse_input(input: &DeriveInput, name: &str) -> darling::Result<BoundedDerive> {
+    if let syn::Data::Union(ref data) = input.data {
+        return Err(darling::Error::custom(format!(
+            "`derive_bounded::{name}` can only be derived on structs and enums, not on unions"
+        ))
+        .with_span(&data.union_token));
+    }
+
+    let mut derive = BoundedDerive::from_derive_input(input)?;
+    // darling matches the paths with their tokens spaced, it forwards only the bare `bounded_to`
+    derive.attrs.extend(
+        input
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.segments.len() > 1 && is_bounded_to(&attr.path))
+            .cloned(),
+    );
+
+    Ok(derive)
+}
+
+/// Reject structs ending with a slice, a `str` or a trait object, `name` requires `Sized`
+fn reject_unsized(input: &BoundedDerive, name: &str) -> darling::Result<()> {
+    let ty = match struct_fields(input).last() {
+        Some(field) => &field.ty,
+        None => return Ok(()),
+    };
+    let is_unsized = match ty {
+        syn::Type::Slice(_) | syn::Type::TraitObject(_) => true,
+        syn::Type::Path(TypePath { qself: None, path }) => path.is_ident("str"),
+        _ => false,
+    };
+
+    if is_unsized {
+        return Err(darling::Error::custom(format!(
+            "`derive_bounded::{name}` cannot be derived on unsized structs"
+        ))
+        .with_span(ty));
+    }
+    Ok(())
+}
+
+fn common_bounded(
+    input: &DeriveInput,
+    name: &str,
+    generator: Generator,
+    bound: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    parse_input(input, name)
+        .and_then(|derive| expand(&derive, generator, bound))
+        .map_err(syn::Error::from)
+}
+
+/// Expand `#[derive(derive_bounded::Default)]` on `input`
+pub fn derive_default(input: DeriveInput) -> syn::Result<TokenStream2> {
+    parse_input(&input, "Default")
+        .and_then(|derive| {
+            reject_unsized(&derive, "Default")?;
+            expand(
+                &derive,
+                default_generator(),
+                quote! { ::std::default::Default },
+            )
+        })
+        .map_err(syn::Error::from)
+}
+
+/// Expand `#[derive(derive_bounded::Clone)]` on `input`
+pub fn derive_clone(input: DeriveInput) -> syn::Result<TokenStream2> {
+    parse_input(&input, "Clone")
+        .and_then(|derive| {
+            reject_unsized(&derive, "Clone")?;
+            let mut tokens = expand(&derive, clone_generator(), quote! { ::std::clone::Clone })?;
+            tokens.extend(boxed_clone(&derive));
+            Ok(tokens)
+        })
+        .map_err(syn::Error::from)
+}
+
+/// Expand `#[derive(derive_bounded::Debug)]` on `input`
+pub fn derive_debug(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(
+        &input,
+        "Debug",
+        debug_generator(),
+        quote! { ::std::fmt::Debug },
+    )
+}
+
+/// Expand `#[derive(derive_bounded::PartialEq)]` on `input`
+pub fn derive_partial_eq(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(
+        &input,
+        "PartialEq",
+        partial_eq_generator(),
+        quote! { ::std::cmp::PartialEq },
+    )
+}
+
+/// Expand `#[derive(derive_bounded::Eq)]` on `input`
+pub fn derive_eq(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(&input, "Eq", eq_generator(), quote! { ::std::cmp::Eq })
+}
+
+/// Expand `#[derive(derive_bounded::Hash)]` on `input`
+pub fn derive_hash(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(
+        &input,
+        "Hash",
+        hash_generator(),
+        quote! { ::std::hash::Hash },
+    )
+}
+
+/// Expand `#[derive(derive_bounded::HashEq)]` on `input`
+pub fn derive_hash_eq(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let derive = parse_input(&input, "HashEq")?;
+
+    [
+        (partial_eq_generator(), quote! { ::std::cmp::PartialEq }),
+        (eq_generator(), quote! { ::std::cmp::Eq }),
+        (hash_generator(), quote! { ::std::hash::Hash }),
+    ]
+    .into_iter()
+    .map(|(generator, bound)| expand(&derive, generator, bound))
+    .collect::<darling::Result<_>>()
+    .map_err(syn::Error::from)
+}
+
+/// Expand `#[derive(derive_bounded::PartialOrd)]` on `input`
+pub fn derive_partial_ord(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(
+        &input,
+        "PartialOrd",
+        partial_ord_generator(),
+        quote! { ::std::cmp::PartialOrd },
+    )
+}
+
+/// Expand `#[derive(derive_bounded::Ord)]` on `input`
+pub fn derive_ord(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(&input, "Ord", ord_generator(), quote! { ::std::cmp::Ord })
+}
+
+/// Expand `#[derive(derive_bounded::TotalOrd)]` on `input`
+///
+/// `PartialEq`, `Eq` and `Hash` use the fields selected for `PartialOrd` and `Ord`, so the five
+/// impls agree.
+pub fn derive_total_ord(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let derive = parse_input(&input, "TotalOrd")?;
+
+    let hash: HashAttr = parse_attrs(&derive.attrs, "hash")?;
+    if hash.named && derive.partial_ord_only_fields.is_some() {
+        return Err(darling::Error::custom(
+            "`hash(named)` hashes every field, it cannot be used with `partial_ord_only_fields`",
+        )
+        .with_span(&derive.ident)
+        .into());
+    }
+    reject_inconsistent(&derive)?;
+
+    [
+        (
+            Generator {
+                select_fields: ord_fields,
+                ..partial_eq_generator()
+            },
+            quote! { ::std::cmp::PartialEq },
+        ),
+        (
+            Generator {
+                select_fields: ord_fields,
+                ..eq_generator()
+            },
+            quote! { ::std::cmp::Eq },
+        ),
+        (
+            Generator {
+                select_fields: ord_fields,
+                ..hash_generator()
+            },
+            quote! { ::std::hash::Hash },
+        ),
+        (partial_ord_generator(), quote! { ::std::cmp::PartialOrd }),
+        (ord_generator(), quote! { ::std::cmp::Ord }),
+    ]
+    .into_iter()
+    .map(|(generator, bound)| expand(&derive, generator, bound))
+    .collect::<darling::Result<_>>()
+    .map_err(syn::Error::from)
+}
+
+/// Reject the options comparing or hashing the values in a way the other `TotalOrd` impls do not
+fn reject_inconsistent(input: &BoundedDerive) -> darling::Result<()> {
+    let mut errors = darling::Error::accumulator();
+    let mut reject = |options: &[(bool, &str)], span: proc_macro2::Span| {
+        for (_, option) in options.iter().filter(|(set, _)| *set) {
+            errors.push(
+                darling::Error::custom(format!(
+                    "`{option}` would make the `PartialEq`, `Hash` and `Ord` impls disagree, \
+                     it cannot be used with `TotalOrd`"
+                ))
+                .with_span(&span),
+            );
+        }
+    };
+
+    let eq: PartialEqAttr = parse_attrs(&input.attrs, "partial_eq")?;
+    reject(
+        &[
+            (eq.with.is_some(), "partial_eq(with)"),
+            (eq.approx, "partial_eq(approx)"),
+        ],
+        input.ident.span(),
+    );
+
+    let fields: Vec<&syn::Field> = match input.data {
+        darling::ast::Data::Struct(ref fields) => fields.iter().collect(),
+        darling::ast::Data::Enum(ref variants) => variants
+            .iter()
+            .flat_map(|variant| &variant.fields)
+            .collect(),
+    };
+    for field in fields {
+        let eq: PartialEqFieldAttr = parse_attrs(&field.attrs, "partial_eq")?;
+        let hash: HashFieldAttr = parse_attrs(&field.attrs, "hash")?;
+        let ord: OrdAttr = parse_attrs(&field.attrs, "ord")?;
+        reject(
+            &[
+                (eq.unordered, "partial_eq(unordered)"),
+                (eq.total_float, "partial_eq(total_float)"),
+                (hash.unordered, "hash(unordered)"),
+                (ord.with.is_some(), "ord(with)"),
+            ],
+            field.span(),
+        );
+    }
+
+    errors.finish()
+}
+
+/// Expand `#[derive(derive_bounded::Display)]` on `input`
+pub fn derive_display(input: DeriveInput) -> syn::Result<TokenStream2> {
+    common_bounded(
+        &input,
+        "Display",
+        display_generator(),
+        quote! { ::std::fmt::Display },
+    )
+}
+
+/// Expand `#[derive_bounded::bounded(derive(...), to(...))]` on `item`
+///
+/// The single identifiers in `derive` name the `derive_bounded` derives, the types in `to` are
+/// forwarded as `#[bounded_to(...)]`.
+pub fn bounded(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let args: UmbrellaArgs = syn::parse2(args)?;
+    let derives = args.derives.iter().map(|path| match path.get_ident() {
+        Some(ident) => quote! { ::derive_bounded::#ident },
+        None => quote! { #path },
+    });
+    let types = &args.types;
+    let bounded_to = types
+        .is_empty()
+        .not()
+        .then(|| quote! { #[bounded_to(#types)] });
+
+    Ok(quote! {
+        #[derive(#(#derives),*)]
+        #bounded_to
+        #item
+    })
+}
+
+/// Expand `#[derive_bounded::bounded_to(...)]` on `item`
+///
+/// The derives coming before the attribute read it as it is, it is forwarded as
+/// `#[bounded_to(...)]` after the derives still on the item.
+pub fn bounded_to(args: TokenStream2, item: TokenStream2) -> syn::Result<TokenStream2> {
+    let mut input: DeriveInput = syn::parse2(item)?;
+    if input.attrs.iter().any(|attr| attr.path.is_ident("derive")) {
+        input.attrs.push(parse_quote! { #[bounded_to(#args)] });
+    }
+
+    Ok(quote! { #input })
+}
+
+/// `bounded_to` or its qualified form `derive_bounded::bounded_to`
+fn is_bounded_to(path: &syn::Path) -> bool {
+    match path.segments.len() {
+        1 => path.is_ident("bounded_to"),
+        2 => path.segments[0].ident == "derive_bounded" && path.segments[1].ident == "bounded_to",
+        _ => false,
+    }
+}
+
+/// Expand `#[derive(derive_bounded::Wrapper)]` on `input`
+#[cfg(feature = "wrapper")]
+pub fn derive_wrapper(input: DeriveInput) -> syn::Result<TokenStream2> {
+    parse_input(&input, "Wrapper")
+        .and_then(|derive| expand_wrapper(&derive))
+        .map_err(syn::Error::from)
+}
+
+/// Expand `#[derive(derive_bounded::FromStr)]` on `input`
+pub fn derive_from_str(input: DeriveInput) -> syn::Result<TokenStream2> {
+    parse_input(&input, "FromStr")
+        .and_then(|derive| expand_from_str(&derive))
+        .map_err(syn::Error::from)
+}
+
+fn expand(
+    default: &BoundedDerive,
+    generator: Generator,
+    bound: TokenStream2,
+) -> darling::Result<TokenStream2> {
+    let type_params = default.generics.declared_type_params();
+    let mut generics = default.generics.clone();
+
+    let mut types = Vec::new();
+    let mut explicit = Vec::new();
+    for attr in default
+        .attrs
+        .iter()
+        .filter(|attr| is_bounded_to(&attr.path))
+    {
+        match syn::parse2::<BoundedAttr>(attr.tokens.clone()) {
+            Ok(ba) => {
+                for item in ba.items {
+                    match item {
+                        BoundedItem::Type(ty) => types.push(ty),
+                        BoundedItem::Predicate(predicate) => explicit.push(predicate),
+                    }
+                }
+            }
+            Err(err) => return Err(err.into()),
+        }
+    }
+    // The explicit predicates are kept, only the bounded types are replaced
+    if let Some(scoped) = (generator.scoped_types)(default) {
+        types = scoped.0.clone();
+    }
+
+    if let Some(ty) = types.iter().find(|ty| is_derived_type(default, ty)) {
+        return Err(darling::Error::custom(format!(
+            "`{}` cannot be bounded to itself, the bound would require the impl being derived",
+            default.ident
+        ))
+        .with_span(ty));
+    }
+
+    if let Some(ty) = types
+        .iter()
+        .map(ToTokens::to_token_stream)
+        .chain(explicit.iter().map(ToTokens::to_token_stream))
+        .find(|ty| mentions_self(ty.clone()))
+    {
+        return Err(darling::Error::custom(
+            "`Self` cannot be used in bounded_to, name the type parameter instead (e.g. `T::Item`)",
+        )
+        .with_span(&ty));
+    }
+
+    let variants = match default.data {
+        darling::ast::Data::Enum(ref variants) => (generator.select_variants)(variants)?,
+        darling::ast::Data::Struct(_) => Vec::new(),
+    };
+
+    let fields = (generator.select_fields)(default)?;
+
+    let mut lengths = ParamLengths {
+        type_params: &type_params,
+        found: Vec::new(),
+    };
+    for field in fields
+        .iter()
+        .copied()
+        .chain(variants.iter().flat_map(|variant| variant.fields.iter()))
+    {
+        lengths.visit_type(&field.ty);
+    }
+    if let Some(len) = lengths.found.first() {
+        return Err(darling::Error::custom(
+            "array lengths depending on type parameters cannot be bounded, use a const generic",
+        )
+        .with_span(len));
+    }
+
+    let (inferred, predicates) = match default.data {
+        darling::ast::Data::Struct(_) => {
+            field_bounds(generator.field_bound, fields.iter().copied())?
+        }
+        darling::ast::Data::Enum(_) => field_bounds(
+            generator.field_bound,
+            variants.iter().flat_map(|variant| variant.fields.iter()),
+        )?,
+    };
+
+    // `T::A` stands for `U` if `T: Trait<A = U>`
+    let equalities = associated_equalities(&default.generics);
+    // A single pass over the fields, repeated types only grow the set they are collected in, so
+    // the expansion stays linear in the number of fields (see `benches/expand.rs`)
+    let type_params_in_body = inferred.iter().fold(IdentRefSet::default(), |mut used, f| {
+        let mut ty = f.ty.clone();
+        ResolveEqualities(&equalities).visit_type_mut(&mut ty);
+        used.extend(field_type_params(
+            &ty,
+            default.markers(),
+            generator.unconditional,
+            &type_params,
+        ));
+        used
+    });
+
+    // Qualified paths such as `<T as Trait<X>>::Out` cover both `T` and `X`
+    let type_params_in_attrs = types.collect_type_params(&Purpose::Declare.into(), &type_params);
+
+    let leftovers = type_params_in_body
+        .difference(&type_params_in_attrs)
+        .filter(|_| !default.strict.is_present())
+        .map(|&ident| {
+            let path = syn::Path::from(ident.clone());
+            let path = TypePath { qself: None, path };
+            syn::Type::from(path)
+        })
+        .collect::<Vec<_>>();
+
+    let where_self = default
+        .where_self
+        .as_ref()
+        .map(|bounds| {
+            Punctuated::<syn::TypeParamBound, token::Add>::parse_separated_nonempty
+                .parse_str(bounds)
+                .map(|bounds| -> syn::WherePredicate {
+                    parse_quote! { Self: #bounds }
+                })
+                .map_err(darling::Error::from)
+        })
+        .transpose()?;
+
+    // The fields of a packed struct are copied out of it, spanned at the field to point at the
+    // one not implementing `Copy`
+    let packed_copy = fields
+        .iter()
+        .filter(|_| is_packed(default))
+        .map(|field| {
+            let ty = &field.ty;
+            syn::parse2::<syn::WherePredicate>(
+                quote::quote_spanned! { ty.span()=> #ty: ::std::marker::Copy },
+            )
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match (generator.scoped_predicates)(default)? {
+        Some(scoped) => {
+            normalize_generics(bound, &mut generics, std::iter::empty());
+            generics.make_where_clause().predicates.extend(scoped);
+        }
+        None => {
+            normalize_generics(bound, &mut generics, types.iter().chain(leftovers.iter()));
+            generics.make_where_clause().predicates.extend(predicates);
+            generics
+                .make_where_clause()
+                .predicates
+                .extend(explicit.into_iter().map(syn::WherePredicate::Type));
+        }
+    }
+    generics.make_where_clause().predicates.extend(where_self);
+    generics.make_where_clause().predicates.extend(packed_copy);
+    sort_predicates(&mut generics);
+
+    let tokens = match default.data {
+        darling::ast::Data::Struct(ref data) => {
+            match data.style {
+                // Unit structs can be built and matched as `Self {}` as well
+                Style::Struct | Style::Unit => {
+                    // SAFETY: Struct style struct has always named fields
+                    let inner =
+                        TokenStream2::from_iter(fields.iter().map(|f| {
+                            (generator.named_field)(default, f.ident.as_ref().unwrap(), f)
+                        }));
+                    (generator.named_body)(default, generics, inner)
+                }
+                Style::Tuple => {
+                    let inner =
+                        TokenStream2::from_iter(fields.iter().enumerate().map(|(i, f)| {
+                            (generator.unnamed_field)(default, syn::Index::from(i), f)
+                        }));
+                    (generator.unnamed_body)(default, generics, inner)
+                }
+            }
+        }
+        darling::ast::Data::Enum(_) => {
+            let inner = TokenStream2::from_iter(
+                variants
+                    .iter()
+                    .map(|variant| (generator.enum_fields)(variant)),
+            );
+
+            (generator.enum_body)(default, generics, inner)
+        }
+    };
+
+    Ok(rename_crates(default, tokens))
+}
+
+/// Whether the struct is `#[repr(packed)]`
+fn is_packed(input: &BoundedDerive) -> bool {
+    input.attrs.iter().any(|attr| {
+        attr.path.is_ident("repr")
+            && matches!(
+                attr.parse_meta(),
+                Ok(syn::Meta::List(list)) if list.nested.iter().any(|nested| {
+                    matches!(nested, syn::NestedMeta::Meta(meta) if meta.path().is_ident("packed"))
+                })
+            )
+    })
+}
+
+/// The integer type of `#[repr(u8)]` and the like
+fn repr_int(input: &BoundedDerive) -> Option<Ident> {
+    const INTS: [&str; 12] = [
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+    ];
+
+    input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("repr"))
+        .filter_map(|attr| attr.parse_meta().ok())
+        .find_map(|meta| match meta {
+            syn::Meta::List(list) => list.nested.into_iter().find_map(|nested| match nested {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => path
+                    .get_ident()
+                    .filter(|ident| INTS.iter().any(|int| *ident == int))
+                    .cloned(),
+                _ => None,
+            }),
+            _ => None,
+        })
+}
+
+/// Rewrite the absolute paths rooted at the crates listed in `#[bounded(crate_paths(...))]`
+fn rename_crates(input: &BoundedDerive, tokens: TokenStream2) -> TokenStream2 {
+    match input.crate_paths {
+        Some(ref paths) if !paths.is_empty() => rename_roots(paths, tokens),
+        _ => tokens,
+    }
+}
+
+/// Whether `ident` can start or continue a path, keywords as `as`, `mut` or `dyn` cannot
+fn is_path_segment(ident: &Ident) -> bool {
+    // Parsing an `Ident` rejects the keywords, including the ones allowed in paths, but accepts
+    // `dyn`, only reserved since the 2018 edition
+    match ident.to_string().as_str() {
+        "self" | "Self" | "super" | "crate" => true,
+        "dyn" => false,
+        _ => syn::parse2::<Ident>(ident.to_token_stream()).is_ok(),
+    }
+}
+
+fn rename_roots(paths: &HashMap<String, syn::Path>, tokens: TokenStream2) -> TokenStream2 {
+    let tokens: Vec<_> = tokens.into_iter().collect();
+    let mut renamed = TokenStream2::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        // A leading `::` is not preceded by a path segment as in `a::b`, after a `>` it may be
+        // either, as in `impl<T> ::std::clone::Clone` and `<T as A>::b`, but no associated item
+        // is expected to be named after a crate
+        let leading = !matches!(
+            i.checked_sub(1).map(|prev| &tokens[prev]),
+            Some(TokenTree::Ident(prev)) if is_path_segment(prev)
+        );
+        let root = match (&tokens[i], tokens.get(i + 1), tokens.get(i + 2)) {
+            (
+                TokenTree::Punct(first),
+                Some(TokenTree::Punct(second)),
+                Some(TokenTree::Ident(ident)),
+            ) if leading
+                && first.as_char() == ':'
+                && first.spacing() == Spacing::Joint
+                && second.as_char() == ':' =>
+            {
+                paths.get(&ident.to_string())
+            }
+            _ => None,
+        };
+
+        match (root, &tokens[i]) {
+            (Some(path), _) => {
+                path.to_tokens(&mut renamed);
+                i += 3;
+                continue;
+            }
+            (None, TokenTree::Group(group)) => {
+                let mut inner = Group::new(group.delimiter(), rename_roots(paths, group.stream()));
+                inner.set_span(group.span());
+                renamed.extend([TokenTree::Group(inner)]);
+            }
+            (None, token) => renamed.extend([token.clone()]),
+        }
+        i += 1;
+    }
+
+    renamed
+}
+
+/// The place of a struct field, copied into a block if the struct is packed as the field may be
+/// unaligned and cannot be borrowed
+fn field_place(input: &BoundedDerive, place: TokenStream2) -> TokenStream2 {
+    if is_packed(input) {
+        quote! { { #place } }
+    } else {
+        place
+    }
+}
+
+/// Fields of a struct input, empty for enums
+fn struct_fields(input: &BoundedDerive) -> &[syn::Field] {
+    match input.data {
+        darling::ast::Data::Struct(ref fields) => &fields.fields,
+        darling::ast::Data::Enum(_) => &[],
+    }
+}
+
+/// Match on `self`, dereferenced if the enum has no variant as `&Never` is not empty
+fn match_self(arms: TokenStream2) -> TokenStream2 {
+    if arms.is_empty() {
+        quote! { match *self {} }
+    } else {
+        quote! { match self { #arms } }
+    }
+}
+
+/// Field-wise `clone_from`, reusing the resources already owned by `self`
+///
+/// The `via` expressions are written in terms of `self` and the fields of packed structs cannot
+/// be borrowed mutably, so those rely on the default `clone_from`.
+fn clone_from<'a>(
+    input: &BoundedDerive,
+    members: impl Iterator<Item = (TokenStream2, &'a syn::Field)>,
+) -> TokenStream2 {
+    let members = members.collect::<Vec<_>>();
+
+    if members.is_empty()
+        || is_packed(input)
+        || members.iter().any(|(_, field)| clone_via(field).is_some())
+    {
+        return TokenStream2::new();
+    }
+
+    let members = members.into_iter().map(|(member, field)| {
+        clone_from_field(field, quote! { self.#member }, quote! { source.#member })
+    });
+
+    quote! {
+        fn clone_from(&mut self, source: &Self) {
+            #(#members)*
+        }
+    }
+}
+
+/// Variant-wise `clone_from`, cloning in place the fields if both values are the same variant
+fn clone_from_variants(variants: &[syn::Variant]) -> TokenStream2 {
+    if variants.iter().all(|variant| variant.fields.is_empty()) {
+        return TokenStream2::new();
+    }
+
+    let self_ident = Ident::new("self", Span::call_site());
+    let source_ident = Ident::new("source", Span::call_site());
+    let arms = variants
+        .iter()
+        .filter(|variant| !variant.fields.is_empty())
+        .map(|variant| {
+            let self_fields = variant_fields(&self_ident, &variant.fields);
+            let source_fields = variant_fields(&source_ident, &variant.fields);
+            let self_match_arm = unpack_struct(&variant.fields, &self_fields);
+            let source_match_arm = unpack_struct(&variant.fields, &source_fields);
+            let variant_ident = &variant.ident;
+            let fields = variant
+                .fields
+                .iter()
+                .zip(self_fields.iter().zip(source_fields.iter()))
+                .map(|(field, (s, o))| clone_from_field(field, quote! { *#s }, quote! { *#o }));
+
+            quote! {
+                (Self::#variant_ident #self_match_arm, Self::#variant_ident #source_match_arm) => {
+                    #(#fields)*
+                }
+            }
+        });
+
+    quote! {
+        fn clone_from(&mut self, source: &Self) {
+            match (self, source) {
+                #(#arms)*
+                (this, source) => *this = ::std::clone::Clone::clone(source),
+            }
+        }
+    }
+}
+
+/// Clone `source` into the `this` place
+fn clone_from_field(field: &syn::Field, this: TokenStream2, source: TokenStream2) -> TokenStream2 {
+    match clone_with(field) {
+        Some(with) => quote! { #this = #with(&#source); },
+        None => quote! { ::std::clone::Clone::clone_from(&mut #this, &#source); },
+    }
+}
+
+/// The function set with `#[clone(with = "...")]`, if any
+fn clone_with(field: &syn::Field) -> Option<syn::Path> {
+    parse_attrs::<CloneAttr>(&field.attrs, "clone")
+        .ok()
+        .and_then(|attr| attr.with)
+}
+
+/// The expression set with `#[clone(via = "...")]`, if any
+fn clone_via(field: &syn::Field) -> Option<syn::Expr> {
+    parse_attrs::<CloneAttr>(&field.attrs, "clone")
+        .ok()
+        .and_then(|attr| attr.via)
+}
+
+/// Whether the type is syntactically `Copy`: a primitive, a shared reference or an array or
+/// tuple of those
+fn is_trivially_copy(ty: &syn::Type) -> bool {
+    match ty {
+        syn::Type::Path(TypePath { qself: None, path }) => path
+            .get_ident()
+            .is_some_and(|ident| PRIMITIVES.iter().any(|primitive| ident == primitive)),
+        syn::Type::Reference(reference) => reference.mutability.is_none(),
+        syn::Type::Array(array) => is_trivially_copy(&array.elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().all(is_trivially_copy),
+        syn::Type::Paren(syn::TypeParen { elem, .. })
+        | syn::Type::Group(syn::TypeGroup { elem, .. }) => is_trivially_copy(elem),
+        _ => false,
+    }
+}
+
+/// Whether `#[bounded(clone_copy)]` is set and every field is trivially `Copy` without a
+/// `#[clone]` attribute
+///
+/// Types with type parameters are not copied as their `Copy` impl may require more bounds.
+fn clone_copies(input: &BoundedDerive) -> bool {
+    let mut fields: Box<dyn Iterator<Item = &syn::Field>> = match input.data {
+        darling::ast::Data::Struct(ref fields) => Box::new(fields.iter()),
+        darling::ast::Data::Enum(ref variants) => {
+            Box::new(variants.iter().flat_map(|variant| variant.fields.iter()))
+        }
+    };
+
+    input.clone_copy.is_present()
+        && input.generics.type_params().next().is_none()
+        && fields.all(|field| {
+            is_trivially_copy(&field.ty) && !field.attrs.iter().any(|a| a.path.is_ident("clone"))
+        })
+}
+
+/// The trait object owned by the type, that cannot be cloned
+fn owned_trait_object(ty: &syn::Type) -> Option<&syn::TypeTraitObject> {
+    match ty {
+        syn::Type::TraitObject(object) => Some(object),
+        syn::Type::Path(_) if is_shared(ty) || is_marker(ty, &[]) => None,
+        syn::Type::Path(TypePath { path, .. }) => path
+            .segments
+            .iter()
+            .filter_map(|segment| match segment.arguments {
+                syn::PathArguments::AngleBracketed(ref args) => Some(args.args.iter()),
+                _ => None,
+            })
+            .flatten()
+            .find_map(|arg| match arg {
+                syn::GenericArgument::Type(ty) => owned_trait_object(ty),
+                _ => None,
+            }),
+        syn::Type::Array(array) => owned_trait_object(&array.elem),
+        syn::Type::Group(group) => owned_trait_object(&group.elem),
+        syn::Type::Paren(paren) => owned_trait_object(&paren.elem),
+        syn::Type::Slice(slice) => owned_trait_object(&slice.elem),
+        syn::Type::Tuple(tuple) => tuple.elems.iter().find_map(owned_trait_object),
+        _ => None,
+    }
+}
+
+/// Whether the trait object is a closure, as `dyn Fn() -> T::A`
+fn is_closure(object: &syn::TypeTraitObject) -> bool {
+    object.bounds.iter().any(|bound| match bound {
+        syn::TypeParamBound::Trait(bound) => bound
+            .path
+            .segments
+            .last()
+            .is_some_and(|last| ["Fn", "FnMut", "FnOnce"].iter().any(|f| last.ident == f)),
+        _ => false,
+    })
+}
+
+fn default_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::default::Default for #name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        Self {
+                            #inner
+                        }
+                    }
+                }
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::default::Default for #name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        Self(
+                            #inner
+                        )
+                    }
+                }
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::default::Default for #name #ty_generics #where_clause {
+                    fn default() -> Self {
+                        #inner
+                    }
+                }
+            }
+        },
+
+        named_field: |_input, ident: &Ident, field| -> TokenStream2 {
+            let value = default_field(field);
+            quote! { #ident: #value, }
+        },
+
+        unnamed_field: |_input, _index, field| -> TokenStream2 {
+            let value = default_field(field);
+            quote! { #value, }
+        },
+
+        enum_fields: |variant| -> TokenStream2 {
+            let variant_ident = &variant.ident;
+
+            match variant.fields {
+                Fields::Named(ref named) => {
+                    let inner = TokenStream2::from_iter(named.named.iter().map(|f| {
+                        let value = default_field(f);
+                        let f = f.ident.as_ref().unwrap();
+                        quote! { #f: #value, }
+                    }));
+                    quote! { Self:: #variant_ident { #inner } }
+                }
+                Fields::Unnamed(ref unnamed) => {
+                    let inner = TokenStream2::from_iter(unnamed.unnamed.iter().map(|f| {
+                        let value = default_field(f);
+                        quote! { #value, }
+                    }));
+                    quote! { Self:: #variant_ident ( #inner ) }
+                }
+                Fields::Unit => quote! { Self:: #variant_ident },
+            }
+        },
+
+        // Fields with an explicit value do not need to implement Default
+        field_bound: |field| {
+            let attr = DefaultAttr::parse(field)?;
+            Ok((attr.value.is_some() || attr.with.is_some()).then(Vec::new))
+        },
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| {
+            let mut defaults = variants.iter().filter(|variant| {
+                variant
+                    .attrs
+                    .iter()
+                    .any(|attr| attr.path.is_ident("default"))
+            });
+
+            match (defaults.next(), defaults.next()) {
+                (Some(variant), None) => Ok(vec![variant]),
+                (None, _) => Err(darling::Error::unsupported_shape(
+                    "enum default requires a #[default] variant",
+                )),
+                (Some(_), Some(extra)) => {
+                    Err(darling::Error::custom("multiple #[default] variants")
+                        .with_span(&extra.ident))
+                }
+            }
+        },
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+/// Inherent `boxed_clone` method, if `#[bounded(boxed_clone)]` is set
+fn boxed_clone(input: &BoundedDerive) -> Option<TokenStream2> {
+    input.boxed_clone.is_present().then(|| {
+        let name = &input.ident;
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { Self: ::std::clone::Clone });
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        let tokens = quote! {
+            impl #impl_generics #name #ty_generics #where_clause {
+                /// Clone into a new box
+                pub fn boxed_clone(&self) -> ::std::boxed::Box<Self> {
+                    ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+                }
+            }
+        };
+        rename_crates(input, tokens)
+    })
+}
+
+/// `Clone` impl copying `*self`, with the default `clone_from`
+fn copy_clone_impl(input: &BoundedDerive, generics: Generics) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::std::clone::Clone for #name #ty_generics #where_clause {
+            #[inline]
+            fn clone(&self) -> Self {
+                *self
+            }
+        }
+    }
+}
+
+fn clone_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            if clone_copies(input) {
+                return copy_clone_impl(input, generics);
+            }
+
+            let clone_from = clone_from(
+                input,
+                struct_fields(input).iter().map(|f| {
+                    let ident = f.ident.as_ref().unwrap();
+                    (quote! { #ident }, f)
+                }),
+            );
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::clone::Clone for #name #ty_generics #where_clause {
+                    fn clone(&self) -> Self {
+                        Self {
+                            #inner
+                        }
+                    }
+
+                    #clone_from
+                }
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            if clone_copies(input) {
+                return copy_clone_impl(input, generics);
+            }
+
+            let clone_from = clone_from(
+                input,
+                struct_fields(input).iter().enumerate().map(|(i, f)| {
+                    let index = syn::Index::from(i);
+                    (quote! { #index }, f)
+                }),
+            );
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::clone::Clone for #name #ty_generics #where_clause {
+                    fn clone(&self) -> Self {
+                        Self (
+                            #inner
+                        )
+                    }
+
+                    #clone_from
+                }
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            if let darling::ast::Data::Enum(ref variants) = input.data {
+                let mut errors = darling::Error::accumulator();
+                for field in variants.iter().flat_map(|variant| variant.fields.iter()) {
+                    if let Some(via) = clone_via(field) {
+                        errors.push(
+                            darling::Error::custom("`via` is supported only on struct fields")
+                                .with_span(&via),
+                        );
+                    }
+                }
+                if let Err(err) = errors.finish() {
+                    return err.write_errors();
+                }
+            }
+            if clone_copies(input) {
+                return copy_clone_impl(input, generics);
+            }
+            let inner = match_self(inner);
+            let clone_from = match input.data {
+                darling::ast::Data::Enum(ref variants) => clone_from_variants(variants),
+                darling::ast::Data::Struct(_) => TokenStream2::new(),
+            };
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::clone::Clone for #name #ty_generics #where_clause {
+                    fn clone(&self) -> Self {
+                        #inner
+                    }
+
+                    #clone_from
+                }
+            }
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            match (clone_with(field), clone_via(field)) {
+                (_, Some(via)) => quote! { #ident: #via, },
+                (Some(with), None) => {
+                    let this = field_place(input, quote! { self.#ident });
+                    quote! { #ident: #with(&#this), }
+                }
+                (None, None) => {
+                    let this = field_place(input, quote! { self.#ident });
+                    quote! { #ident: ::std::clone::Clone::clone(&#this), }
+                }
+            }
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            match (clone_with(field), clone_via(field)) {
+                (_, Some(via)) => quote! { #via, },
+                (Some(with), None) => {
+                    let this = field_place(input, quote! { self.#index });
+                    quote! { #with(&#this), }
+                }
+                (None, None) => {
+                    let this = field_place(input, quote! { self.#index });
+                    quote! { ::std::clone::Clone::clone(&#this), }
+                }
+            }
+        },
+
+        enum_fields: |variant| -> TokenStream2 {
+            let self_ident = Ident::new("self", Span::call_site());
+            let self_fields = variant_fields(&self_ident, &variant.fields);
+            let match_arm = unpack_struct(&variant.fields, &self_fields);
+            let variant_ident = &variant.ident;
+
+            let inner = match variant.fields {
+                Fields::Named(ref named) => {
+                    let inner = TokenStream2::from_iter(
+                        named.named.iter().zip(self_fields.iter()).map(|(f, s)| {
+                            let ident = f.ident.as_ref().unwrap();
+                            match clone_with(f) {
+                                Some(with) => quote! { #ident: #with(#s), },
+                                None => quote! { #ident: ::std::clone::Clone::clone(#s), },
+                            }
+                        }),
+                    );
+                    quote! {
+                        Self:: #variant_ident { #inner }
+                    }
+                }
+                Fields::Unnamed(ref unnamed) => {
+                    let inner = TokenStream2::from_iter(
+                        unnamed.unnamed.iter().zip(self_fields.iter()).map(
+                            |(f, s)| match clone_with(f) {
+                                Some(with) => quote! { #with(#s), },
+                                None => quote! { ::std::clone::Clone::clone(#s), },
+                            },
+                        ),
+                    );
+                    quote! {
+                        Self:: #variant_ident ( #inner )
+                    }
+                }
+                Fields::Unit => quote! { Self:: #variant_ident },
+            };
+
+            quote! { Self:: #variant_ident #match_arm => #inner, }
+        },
+
+        field_bound: |field| {
+            let attr: CloneAttr = parse_attrs(&field.attrs, "clone")?;
+            if let (Some(_), Some(via)) = (&attr.with, &attr.via) {
+                return Err(
+                    darling::Error::custom("`with` and `via` cannot be combined").with_span(via),
+                );
+            }
+            if attr.with.is_some() || attr.via.is_some() {
+                return Ok(Some(attr.bound.unwrap_or_default()));
+            }
+            match owned_trait_object(&field.ty) {
+                Some(object) if is_closure(object) => {
+                    return Err(darling::Error::custom(
+                        "closures cannot be cloned, use `#[clone(with = \"...\")]` to rebuild this field",
+                    )
+                    .with_span(&field.ty))
+                }
+                Some(_) => {
+                    return Err(darling::Error::custom(
+                        "trait objects cannot be cloned, use `#[clone(with = \"...\")]` for this field",
+                    )
+                    .with_span(&field.ty))
+                }
+                None => {}
+            }
+            Ok(attr.bound)
+        },
+        // Shared references and reference counted pointers are cloned without their pointee
+        unconditional: |ty| {
+            matches!(ty, syn::Type::Reference(reference) if reference.mutability.is_none())
+                || is_shared(ty)
+        },
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+fn debug_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            let s = name.to_string();
+            let pretty = force_pretty(input);
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::fmt::Debug for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        #pretty
+                        let mut builder = f.debug_struct(#s);
+                        #inner
+                        builder.finish()
+                    }
+                }
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            let s = name.to_string();
+            let pretty = force_pretty(input);
+            let builder = if input.debug_tuple_as_struct.is_present() {
+                quote! { debug_struct }
+            } else {
+                quote! { debug_tuple }
+            };
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::fmt::Debug for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        #pretty
+                        let mut builder = f.#builder(#s);
+                        #inner
+                        builder.finish()
+                    }
+                }
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            let inner = match discriminant_arms(input) {
+                Ok(Some(arms)) => arms,
+                Ok(None) => inner,
+                Err(err) => return err.write_errors(),
+            };
+            let inner = match_self(inner);
+            let pretty = force_pretty(input);
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::fmt::Debug for #name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        #pretty
+                        #inner
+                    }
+                }
+            }
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            debug_field(field, Some(ident.to_string()), quote! { &#this })
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            let name = input
+                .debug_tuple_as_struct
+                .is_present()
+                .then(|| index.index.to_string());
+            debug_field(field, name, quote! { &#this })
+        },
+        enum_fields: |variant| -> TokenStream2 {
+            let self_ident = Ident::new("self", Span::call_site());
+            let self_fields = variant_fields(&self_ident, &variant.fields);
+            let match_arm = unpack_struct(&variant.fields, &self_fields);
+            let variant_ident = &variant.ident;
+            let s = variant_ident.to_string();
+            let inner = match variant.fields {
+                Fields::Named(ref named) => {
+                    let inner =
+                        TokenStream2::from_iter(named.named.iter().zip(self_fields.iter()).map(
+                            |(field, f)| {
+                                let s = field.ident.as_ref().unwrap().to_string();
+                                debug_field(field, Some(s), quote! { #f })
+                            },
+                        ));
+                    quote! {{
+                        let mut builder = f.debug_struct(#s);
+                        #inner
+                        builder.finish()
+                    }}
+                }
+                Fields::Unnamed(ref unnamed) => {
+                    let inner = TokenStream2::from_iter(
+                        unnamed
+                            .unnamed
+                            .iter()
+                            .zip(self_fields.iter())
+                            .map(|(field, f)| debug_field(field, None, quote! { #f })),
+                    );
+                    quote! {{
+                        let mut builder = f.debug_tuple(#s);
+                        #inner
+                        builder.finish()
+                    }}
+                }
+                Fields::Unit => quote! { f.write_str(#s) },
+            };
+
+            quote! { Self:: #variant_ident #match_arm => #inner, }
+        },
+
+        // Debug has no explicit field bounds, only validate the field options
+        field_bound: |field| parse_attrs::<DebugFieldAttr>(&field.attrs, "debug").map(|_| None),
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: debug_bound,
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+/// Predicates set by `#[debug(bound = "...")]`
+fn debug_bound(input: &BoundedDerive) -> darling::Result<Option<Vec<syn::WherePredicate>>> {
+    let attr: DebugAttr = parse_attrs(&input.attrs, "debug")?;
+
+    attr.bound
+        .map(|bound| {
+            Punctuated::<syn::WherePredicate, Comma>::parse_terminated
+                .parse_str(&bound)
+                .map(|predicates| predicates.into_iter().collect())
+                .map_err(darling::Error::from)
+        })
+        .transpose()
+}
+
+/// Add a field to the `builder`, unless its `#[debug(skip_if = "...")]` predicate holds
+///
+/// Skipped tuple fields are printed as `..` to keep the position of the others.
+fn debug_field(field: &syn::Field, name: Option<String>, value: TokenStream2) -> TokenStream2 {
+    let attr: DebugFieldAttr = parse_attrs(&field.attrs, "debug").unwrap_or_default();
+    let shown = match attr.max_len {
+        Some(max_len) => truncated(value.clone(), max_len),
+        None => value.clone(),
+    };
+    // Borrowed again, as unsized fields cannot be cast to `&dyn Debug`
+    let call = match name {
+        Some(ref name) => quote! { builder.field(#name, &#shown); },
+        None => quote! { builder.field(&#shown); },
+    };
+
+    match (attr.skip_if, name) {
+        (Some(predicate), Some(_)) => quote! { if !#predicate(#value) { #call } },
+        (Some(predicate), None) => quote! {
+            if #predicate(#value) {
+                builder.field(&::std::format_args!(".."));
+            } else {
+                #call
+            }
+        },
+        (None, _) => call,
+    }
+}
+
+/// Format again with the alternate flag set if `debug_pretty` is set and the flag is not
+fn force_pretty(input: &BoundedDerive) -> Option<TokenStream2> {
+    input.debug_pretty.is_present().then(|| {
+        quote! {
+            if !f.alternate() {
+                return ::std::write!(f, "{:#?}", self);
+            }
+        }
+    })
+}
+
+/// Reference to a list printing up to `max_len` items of the collection `value`, then `..`
+fn truncated(value: TokenStream2, max_len: usize) -> TokenStream2 {
+    quote! {
+        &{
+            struct Truncated<'a, C: ?::std::marker::Sized>(&'a C, usize);
+
+            impl<'a, C: ?::std::marker::Sized> ::std::fmt::Debug for Truncated<'a, C>
+            where
+                &'a C: ::std::iter::IntoIterator,
+                <&'a C as ::std::iter::IntoIterator>::Item: ::std::fmt::Debug,
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    let mut list = f.debug_list();
+                    let mut items = ::std::iter::IntoIterator::into_iter(self.0);
+                    list.entries(::std::iter::Iterator::take(&mut items, self.1));
+                    if ::std::iter::Iterator::next(&mut items).is_some() {
+                        list.entry(&::std::format_args!(".."));
+                    }
+                    list.finish()
+                }
+            }
+
+            Truncated(#value, #max_len)
+        }
+    }
+}
+
+/// Match arms printing the variant name and its discriminant, if `#[debug(with_discriminant)]` is set
+fn discriminant_arms(input: &BoundedDerive) -> darling::Result<Option<TokenStream2>> {
+    let attr: DebugAttr = parse_attrs(&input.attrs, "debug")?;
+    let variants = match input.data {
+        darling::ast::Data::Enum(ref variants) if attr.with_discriminant => variants,
+        _ => return Ok(None),
+    };
+
+    let mut errors = darling::Error::accumulator();
+    let mut arms = TokenStream2::new();
+    let repr = repr_int(input).unwrap_or_else(|| Ident::new("isize", Span::call_site()));
+
+    for variant in variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            errors.push(
+                darling::Error::custom("with_discriminant requires fieldless variants")
+                    .with_span(&variant.fields),
+            );
+            continue;
+        }
+
+        let variant_ident = &variant.ident;
+        let s = variant_ident.to_string();
+        arms.extend(quote! {
+            Self::#variant_ident => ::std::write!(f, "{} ({})", #s, Self::#variant_ident as #repr),
+        });
+    }
+
+    errors.finish_with(Some(arms))
+}
+
+fn partial_eq_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let inner = conjunction(inner);
+            let with = partial_eq_with(input).unwrap_or_else(|err| err.write_errors());
+            let approx = approx_eq(input, &generics).unwrap_or_else(|err| err.write_errors());
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        #inner
+                    }
+                }
+
+                #with
+                #approx
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let inner = conjunction(inner);
+            let with = partial_eq_with(input).unwrap_or_else(|err| err.write_errors());
+            let approx = approx_eq(input, &generics).unwrap_or_else(|err| err.write_errors());
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        #inner
+                    }
+                }
+
+                #with
+                #approx
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let with = partial_eq_with(input).unwrap_or_else(|err| err.write_errors());
+            let approx = approx_eq(input, &generics).unwrap_or_else(|err| err.write_errors());
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::PartialEq for #name #ty_generics #where_clause {
+                    fn eq(&self, other: &Self) -> bool {
+                        match (self, other) {
+                            #inner
+                            _ => false,
+                        }
+                    }
+                }
+
+                #with
+                #approx
+            }
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            let other = field_place(input, quote! { other.#ident });
+            eq_field(field, quote! { &#this }, quote! { &#other })
+                .unwrap_or_else(|| quote! { && ::std::cmp::PartialEq::eq(&#this, &#other) })
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            let other = field_place(input, quote! { other.#index });
+            eq_field(field, quote! { &#this }, quote! { &#other })
+                .unwrap_or_else(|| quote! { && ::std::cmp::PartialEq::eq(&#this, &#other) })
+        },
+
+        enum_fields: |variant| -> TokenStream2 {
+            let self_ident = Ident::new("self", Span::call_site());
+            let self_fields = variant_fields(&self_ident, &variant.fields);
+            let other_ident = Ident::new("other", Span::call_site());
+            let other_fields = variant_fields(&other_ident, &variant.fields);
+            let self_match_arm = unpack_struct(&variant.fields, &self_fields);
+            let other_match_arm = unpack_struct(&variant.fields, &other_fields);
+            let variant_ident = &variant.ident;
+            let variant_ident = quote! { Self:: #variant_ident };
+
+            let match_arm =
+                quote! { (#variant_ident #self_match_arm, #variant_ident #other_match_arm) };
+
+            let inner = conjunction(TokenStream2::from_iter(
+                variant
+                    .fields
+                    .iter()
+                    .zip(other_fields.iter().zip(self_fields.iter()))
+                    .map(|(field, (o, s))| {
+                        eq_field(field, quote! { #s }, quote! { #o })
+                            .unwrap_or_else(|| quote! { && ::std::cmp::PartialEq::eq(#s, #o) })
+                    }),
+            ));
+
+            quote! { #match_arm => #inner, }
+        },
+
+        // PartialEq has no explicit field bounds, only validate the field options
+        field_bound: |field| {
+            let attr: PartialEqFieldAttr = parse_attrs(&field.attrs, "partial_eq")?;
+            if attr.unordered && attr.total_float {
+                return Err(darling::Error::custom(
+                    "`unordered` and `total_float` cannot be combined",
+                )
+                .with_span(field));
+            }
+            Ok(None)
+        },
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+fn eq_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, _inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::Eq for #name #ty_generics #where_clause {}
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, _inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::Eq for #name #ty_generics #where_clause {}
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, _inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::cmp::Eq for #name #ty_generics #where_clause {}
+            }
+        },
+
+        named_field: |_input, _ident: &Ident, _field| -> TokenStream2 {
+            quote! {}
+        },
+
+        unnamed_field: |_input, _index, _field| -> TokenStream2 {
+            quote! {}
+        },
+
+        enum_fields: |_variant| -> TokenStream2 {
+            quote! {}
+        },
+
+        field_bound: |_field| Ok(None),
+        unconditional: |_ty| false,
+        scoped_types: |input| input.eq_bound.as_ref(),
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+fn hash_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            let inner = match hash_attr(input) {
+                Ok(attr) if attr.named => named_hash(input),
+                Ok(_) => inner,
+                Err(err) => return err.write_errors(),
+            };
+            let inner = if inner.is_empty() {
+                quote! { let _ = state; }
+            } else {
+                inner
+            };
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+                    fn hash<__H: ::std::hash::Hasher>(&self, state: &mut __H) {
+                        #inner
+                    }
+                }
+            }
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+            if let Err(err) = hash_attr(input) {
+                return err.write_errors();
+            }
+            let inner = if inner.is_empty() {
+                quote! { let _ = state; }
+            } else {
+                inner
+            };
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+                    fn hash<__H: ::std::hash::Hasher>(&self, state: &mut __H) {
+                        #inner
+                    }
+                }
+            }
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            let name = &input.ident;
+            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+            let variant = match hash_attr(input) {
+                Ok(attr) if attr.variant_name => {
+                    let name = variant_name(input);
+                    quote! {
+                        let name = #name;
+                        ::std::hash::Hash::hash(name(self), state);
+                    }
+                }
+                Ok(_) => {
+                    let index = variant_index(input);
+                    quote! {
+                        let index = #index;
+                        ::std::hash::Hash::hash(&index(self), state);
+                    }
+                }
+                Err(err) => return err.write_errors(),
+            };
+            let inner = match_self(inner);
+
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl #impl_generics ::std::hash::Hash for #name #ty_generics #where_clause {
+                    fn hash<__H: ::std::hash::Hasher>(&self, state: &mut __H) {
+                        #variant
+                        #inner
+                    }
+                }
+            }
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            hash_field(field, quote! { &#this })
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            hash_field(field, quote! { &#this })
+        },
+
+        enum_fields: |variant| -> TokenStream2 {
+            let self_ident = Ident::new("self", Span::call_site());
+            let self_fields = variant_fields(&self_ident, &variant.fields);
+            let match_arm = unpack_struct(&variant.fields, &self_fields);
+            let variant_ident = &variant.ident;
+
+            let inner = TokenStream2::from_iter(
+                variant
+                    .fields
+                    .iter()
+                    .zip(self_fields.iter())
+                    .map(|(field, s)| hash_field(field, quote! { #s })),
+            );
+
+            quote! { Self:: #variant_ident #match_arm => { #inner } }
+        },
+
+        // Fields hashed by a function are not bounded, the others rely on inference
+        field_bound: |field| {
+            let attr: HashFieldAttr = parse_attrs(&field.attrs, "hash")?;
+            match attr.with {
+                Some(with) if attr.unordered => Err(darling::Error::custom(
+                    "`unordered` and `with` cannot be combined",
+                )
+                .with_span(&with)),
+                Some(_) => Ok(Some(Vec::new())),
+                None => Ok(None),
+            }
+        },
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+/// `PartialEq` impls against the types set with `#[partial_eq(with = "...")]`, comparing them
+/// with the only field
+fn partial_eq_with(input: &BoundedDerive) -> darling::Result<TokenStream2> {
+    let attr: PartialEqAttr = parse_attrs(&input.attrs, "partial_eq")?;
+    let types = match attr.with {
+        Some(BoundedTypes(types)) => types,
+        None => return Ok(TokenStream2::new()),
+    };
+    let field = match (&input.data, struct_fields(input)) {
+        (darling::ast::Data::Struct(_), [field]) => field,
+        _ => {
+            return Err(
+                darling::Error::custom("`with` requires a struct with a single field")
+                    .with_span(&input.ident),
+            )
+        }
+    };
+
+    let member = match field.ident {
+        Some(ref ident) => quote! { #ident },
+        None => quote! { 0 },
+    };
+    let field_ty = &field.ty;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, _) = input.generics.split_for_impl();
+
+    Ok(TokenStream2::from_iter(types.iter().map(|ty| {
+        let mut generics = input.generics.clone();
+        generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote! { #field_ty: ::std::cmp::PartialEq<#ty> });
+        let where_clause = &generics.where_clause;
+
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::cmp::PartialEq<#ty> for #name #ty_generics #where_clause {
+                fn eq(&self, other: &#ty) -> bool {
+                    ::std::cmp::PartialEq::eq(&self.#member, other)
+                }
+            }
+        }
+    })))
+}
+
+/// Inherent `approx_eq` set with `#[partial_eq(approx)]`, comparing the `f32` and `f64` fields
+/// within a tolerance and the others as `eq` does
+fn approx_eq(input: &BoundedDerive, generics: &Generics) -> darling::Result<TokenStream2> {
+    let attr: PartialEqAttr = parse_attrs(&input.attrs, "partial_eq")?;
+    if !attr.approx {
+        return Ok(TokenStream2::new());
+    }
+    if let darling::ast::Data::Enum(_) = input.data {
+        return Err(darling::Error::custom("`approx` requires a struct").with_span(&input.ident));
+    }
+
+    let comparisons = TokenStream2::from_iter(struct_fields(input).iter().enumerate().map(
+        |(index, field)| {
+            let member = match field.ident {
+                Some(ref ident) => quote! { #ident },
+                None => {
+                    let index = syn::Index::from(index);
+                    quote! { #index }
+                }
+            };
+            let this = field_place(input, quote! { self.#member });
+            let other = field_place(input, quote! { other.#member });
+            let is_float = matches!(
+                field.ty,
+                syn::Type::Path(TypePath { qself: None, ref path })
+                    if path.is_ident("f32") || path.is_ident("f64")
+            );
+
+            eq_field(field, quote! { &#this }, quote! { &#other }).unwrap_or_else(|| {
+                if is_float {
+                    quote! {
+                        && {
+                            let (this, other) = (#this as f64, #other as f64);
+                            this == other || (this - other).abs() <= epsilon
+                        }
+                    }
+                } else {
+                    quote! { && ::std::cmp::PartialEq::eq(&#this, &#other) }
+                }
+            })
+        },
+    ));
+    let comparisons = conjunction(comparisons);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Compare with `other`, the float fields being equal if they differ by `epsilon` at most
+            #[allow(unused_variables)]
+            pub fn approx_eq(&self, other: &Self, epsilon: f64) -> bool {
+                #comparisons
+            }
+        }
+    })
+}
+
+/// Join the `&& ...` comparisons of the fields, `true` if there are none
+fn conjunction(comparisons: TokenStream2) -> TokenStream2 {
+    // Drop the leading `&&`
+    let comparisons: TokenStream2 = comparisons.into_iter().skip(2).collect();
+
+    match comparisons.clone().into_iter().next() {
+        None => quote! { true },
+        // A leading block would be parsed as a statement
+        Some(proc_macro2::TokenTree::Group(group))
+            if group.delimiter() == proc_macro2::Delimiter::Brace =>
+        {
+            quote! { (#comparisons) }
+        }
+        Some(_) => comparisons,
+    }
+}
+
+/// Compare a field as set by `#[partial_eq(...)]`, `this` and `other` being references to it
+fn eq_field(field: &syn::Field, this: TokenStream2, other: TokenStream2) -> Option<TokenStream2> {
+    let attr: PartialEqFieldAttr = parse_attrs(&field.attrs, "partial_eq").unwrap_or_default();
+
+    if attr.unordered {
+        Some(unordered_eq(this, other))
+    } else if attr.total_float {
+        Some(quote! { && ::std::cmp::Ordering::is_eq((#this).total_cmp(#other)) })
+    } else {
+        None
+    }
+}
+
+/// Compare two collections as multisets, counting the matches of every item in both
+fn unordered_eq(this: TokenStream2, other: TokenStream2) -> TokenStream2 {
+    quote! {
+        && {
+            use ::std::iter::{IntoIterator, Iterator};
+
+            let (this, other) = (#this, #other);
+            Iterator::count(IntoIterator::into_iter(this))
+                == Iterator::count(IntoIterator::into_iter(other))
+                && Iterator::all(&mut IntoIterator::into_iter(this), |item| {
+                    let matches = |items| {
+                        Iterator::filter(IntoIterator::into_iter(items), |i| {
+                            ::std::cmp::PartialEq::eq(*i, item)
+                        })
+                    };
+                    Iterator::count(matches(this)) == Iterator::count(matches(other))
+                })
+        }
+    }
+}
+
+/// Hash a field, combining the item hashes with a commutative sum if it is unordered
+///
+/// Equal values must hash the same, so `#[partial_eq(unordered)]` implies `#[hash(unordered)]`.
+fn hash_field(field: &syn::Field, value: TokenStream2) -> TokenStream2 {
+    let hash: HashFieldAttr = parse_attrs(&field.attrs, "hash").unwrap_or_default();
+    let eq: PartialEqFieldAttr = parse_attrs(&field.attrs, "partial_eq").unwrap_or_default();
+
+    if let Some(with) = hash.with {
+        return quote! { #with(#value, state); };
+    }
+
+    if !hash.unordered && !eq.unordered {
+        return quote! { ::std::hash::Hash::hash(#value, state); };
+    }
+
+    quote! {{
+        let mut len = 0usize;
+        let mut sum = 0u64;
+        for item in ::std::iter::IntoIterator::into_iter(#value) {
+            let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+            ::std::hash::Hash::hash(item, &mut hasher);
+            sum = sum.wrapping_add(::std::hash::Hasher::finish(&hasher));
+            len += 1;
+        }
+        ::std::hash::Hash::hash(&len, state);
+        ::std::hash::Hash::hash(&sum, state);
+    }}
+}
+
+/// Hash the fields along with their names, summing the hashes of the pairs
+///
+/// The result does not depend on the declaration order of the fields.
+fn named_hash(input: &BoundedDerive) -> TokenStream2 {
+    let fields = struct_fields(input).iter().map(|field| {
+        // SAFETY: `named` is accepted only on structs with named fields
+        let ident = field.ident.as_ref().unwrap();
+        let name = ident.to_string();
+        let this = field_place(input, quote! { self.#ident });
+        let value = hash_field(field, quote! { &#this });
+
+        quote! {
+            .wrapping_add({
+                let mut hasher = ::std::collections::hash_map::DefaultHasher::new();
+                {
+                    let state = &mut hasher;
+                    ::std::hash::Hash::hash(#name, state);
+                    #value
+                }
+                ::std::hash::Hasher::finish(&hasher)
+            })
+        }
+    });
+
+    quote! {
+        let sum = 0u64 #(#fields)*;
+        ::std::hash::Hash::hash(&sum, state);
+    }
+}
+
+/// Parse `#[hash(...)]`, rejecting `named` on items without field names and `variant_name`
+/// outside enums
+fn hash_attr(input: &BoundedDerive) -> darling::Result<HashAttr> {
+    let attr: HashAttr = parse_attrs(&input.attrs, "hash")?;
+    let (named, is_enum) = match input.data {
+        darling::ast::Data::Struct(ref data) => (data.style != Style::Tuple, false),
+        darling::ast::Data::Enum(_) => (false, true),
+    };
+
+    if attr.named && !named {
+        return Err(
+            darling::Error::custom("`named` requires a struct with named fields")
+                .with_span(&input.ident),
+        );
+    }
+    if attr.variant_name && !is_enum {
+        return Err(
+            darling::Error::custom("`variant_name` requires an enum").with_span(&input.ident)
+        );
+    }
+    Ok(attr)
+}
+
+/// Closure returning the declaration index of the variant, shared by the Hash and Ord impls
+fn variant_index(input: &BoundedDerive) -> TokenStream2 {
+    let variants = match input.data {
+        darling::ast::Data::Enum(ref variants) => variants.as_slice(),
+        darling::ast::Data::Struct(_) => &[],
+    };
+    let index = variants.iter().enumerate().map(|(i, variant)| {
+        let ident = &variant.ident;
+        quote! { Self::#ident { .. } => #i, }
+    });
+
+    quote! {
+        |value: &Self| -> usize {
+            match *value {
+                #(#index)*
+            }
+        }
+    }
+}
+
+/// Closure returning the name of the variant
+fn variant_name(input: &BoundedDerive) -> TokenStream2 {
+    let variants = match input.data {
+        darling::ast::Data::Enum(ref variants) => variants.as_slice(),
+        darling::ast::Data::Struct(_) => &[],
+    };
+    let names = variants.iter().map(|variant| {
+        let ident = &variant.ident;
+        let name = ident.to_string();
+        quote! { Self::#ident { .. } => #name, }
+    });
+
+    quote! {
+        |value: &Self| -> &'static str {
+            match *value {
+                #(#names)*
+            }
+        }
+    }
+}
+
+/// Compare a field, returning early unless it is equal
+fn cmp_field(
+    field: &syn::Field,
+    this: TokenStream2,
+    other: TokenStream2,
+    partial: bool,
+) -> TokenStream2 {
+    let attr: OrdAttr = parse_attrs(&field.attrs, "ord").unwrap_or_default();
+    let (cmp, equal) = match (attr.with, partial) {
+        (Some(with), true) => (
+            quote! { ::std::option::Option::Some(#with(#this, #other)) },
+            quote! { ::std::option::Option::Some(::std::cmp::Ordering::Equal) },
+        ),
+        (Some(with), false) => (
+            quote! { #with(#this, #other) },
+            quote! { ::std::cmp::Ordering::Equal },
+        ),
+        (None, true) => (
+            quote! { ::std::cmp::PartialOrd::partial_cmp(#this, #other) },
+            quote! { ::std::option::Option::Some(::std::cmp::Ordering::Equal) },
+        ),
+        (None, false) => (
+            quote! { ::std::cmp::Ord::cmp(#this, #other) },
+            quote! { ::std::cmp::Ordering::Equal },
+        ),
+    };
+
+    quote! {
+        match #cmp {
+            #equal => {}
+            cmp => return cmp,
+        }
+    }
+}
+
+/// Fields with `#[ord(with = "...")]` are compared by the given function and not bounded
+fn ord_field_bound(field: &syn::Field) -> darling::Result<Option<Vec<syn::WherePredicate>>> {
+    let attr: OrdAttr = parse_attrs(&field.attrs, "ord")?;
+    Ok(attr.with.map(|_| Vec::new()))
+}
+
+/// The `partial_cmp` or `cmp` body of a struct, comparing the fields in declaration order
+fn ord_struct(
+    input: &BoundedDerive,
+    generics: Generics,
+    inner: TokenStream2,
+    partial: bool,
+) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let unused = inner.is_empty().then(|| quote! { let _ = other; });
+
+    if partial {
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    #unused
+                    #inner
+                    ::std::option::Option::Some(::std::cmp::Ordering::Equal)
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::cmp::Ord for #name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    #unused
+                    #inner
+                    ::std::cmp::Ordering::Equal
+                }
+            }
+        }
+    }
+}
+
+/// The `partial_cmp` or `cmp` body of an enum, ordering the variants by declaration index
+fn ord_enum(
+    input: &BoundedDerive,
+    generics: Generics,
+    inner: TokenStream2,
+    partial: bool,
+) -> TokenStream2 {
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let index = variant_index(input);
+    let index_cmp = quote! {
+        {
+            let index = #index;
+            ::std::cmp::Ord::cmp(&index(self), &index(other))
+        }
+    };
+
+    if partial {
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::cmp::PartialOrd for #name #ty_generics #where_clause {
+                fn partial_cmp(&self, other: &Self) -> ::std::option::Option<::std::cmp::Ordering> {
+                    match (self, other) {
+                        #inner
+                        _ => ::std::option::Option::Some(#index_cmp),
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {
+            #[automatically_derived]
+            #[allow(unused_qualifications)]
+            impl #impl_generics ::std::cmp::Ord for #name #ty_generics #where_clause {
+                fn cmp(&self, other: &Self) -> ::std::cmp::Ordering {
+                    match (self, other) {
+                        #inner
+                        _ => #index_cmp,
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Match arm comparing the fields of two values of the same variant
+fn ord_variant(variant: &syn::Variant, partial: bool) -> TokenStream2 {
+    let self_ident = Ident::new("self", Span::call_site());
+    let self_fields = variant_fields(&self_ident, &variant.fields);
+    let other_ident = Ident::new("other", Span::call_site());
+    let other_fields = variant_fields(&other_ident, &variant.fields);
+    let self_match_arm = unpack_struct(&variant.fields, &self_fields);
+    let other_match_arm = unpack_struct(&variant.fields, &other_fields);
+    let variant_ident = &variant.ident;
+
+    let inner = TokenStream2::from_iter(
+        variant
+            .fields
+            .iter()
+            .zip(self_fields.iter().zip(other_fields.iter()))
+            .map(|(field, (s, o))| cmp_field(field, quote! { #s }, quote! { #o }, partial)),
+    );
+    let equal = if partial {
+        quote! { ::std::option::Option::Some(::std::cmp::Ordering::Equal) }
+    } else {
+        quote! { ::std::cmp::Ordering::Equal }
+    };
+
+    quote! {
+        (Self::#variant_ident #self_match_arm, Self::#variant_ident #other_match_arm) => {
+            #inner
+            #equal
+        }
+    }
+}
+
+/// Fields compared by PartialOrd and Ord, restricted and reordered by `partial_ord_only_fields`
+fn ord_fields(input: &BoundedDerive) -> darling::Result<Vec<&syn::Field>> {
+    let only = match input.partial_ord_only_fields {
+        Some(ref only) => only,
+        None => return Ok(struct_fields(input).iter().collect()),
+    };
+
+    match input.data {
+        darling::ast::Data::Struct(ref data) if data.style == Style::Struct => {}
+        _ => {
+            return Err(darling::Error::custom(
+                "`partial_ord_only_fields` needs a struct with named fields",
+            )
+            .with_span(&input.ident))
+        }
+    }
+
+    let mut errors = darling::Error::accumulator();
+    let fields = only
+        .iter()
+        .filter_map(|path| {
+            let field = struct_fields(input).iter().find(|field| {
+                field
+                    .ident
+                    .as_ref()
+                    .is_some_and(|ident| path.is_ident(ident))
+            });
+            if field.is_none() {
+                errors.push(darling::Error::unknown_field_path(path).with_span(path));
+            }
+            field
+        })
+        .collect();
+
+    errors.finish_with(fields)
+}
+
+fn partial_ord_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_struct(input, generics, inner, true)
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_struct(input, generics, inner, true)
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_enum(input, generics, inner, true)
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            let other = field_place(input, quote! { other.#ident });
+            cmp_field(field, quote! { &#this }, quote! { &#other }, true)
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            let other = field_place(input, quote! { other.#index });
+            cmp_field(field, quote! { &#this }, quote! { &#other }, true)
+        },
+
+        enum_fields: |variant| -> TokenStream2 { ord_variant(variant, true) },
+
+        field_bound: ord_field_bound,
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: ord_fields,
+    }
+}
+
+fn ord_generator() -> Generator {
+    Generator {
+        named_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_struct(input, generics, inner, false)
+        },
+
+        unnamed_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_struct(input, generics, inner, false)
+        },
+
+        enum_body: |input: &BoundedDerive, generics: Generics, inner| -> TokenStream2 {
+            ord_enum(input, generics, inner, false)
+        },
+
+        named_field: |input, ident: &Ident, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            let other = field_place(input, quote! { other.#ident });
+            cmp_field(field, quote! { &#this }, quote! { &#other }, false)
+        },
+
+        unnamed_field: |input, index, field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            let other = field_place(input, quote! { other.#index });
+            cmp_field(field, quote! { &#this }, quote! { &#other }, false)
+        },
+
+        enum_fields: |variant| -> TokenStream2 { ord_variant(variant, false) },
+
+        field_bound: ord_field_bound,
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: ord_fields,
+    }
+}
+
+/// `Display` impl writing the `fields`, a list of `&dyn Display`, separated by the `join` string
+fn display_body(input: &BoundedDerive, generics: Generics, fields: TokenStream2) -> TokenStream2 {
+    let attr: DisplayAttr = match parse_attrs(&input.attrs, "display") {
+        Ok(attr) => attr,
+        Err(err) => return err.write_errors(),
+    };
+    let join = match attr.join {
+        Some(join) => join,
+        None => {
+            return darling::Error::custom("expected #[display(join = \"...\")]")
+                .with_span(&input.ident)
+                .write_errors()
+        }
+    };
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::std::fmt::Display for #name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                let fields: &[&dyn ::std::fmt::Display] = &[#fields];
+                for (i, field) in ::std::iter::Iterator::enumerate(fields.iter()) {
+                    if i > 0 {
+                        f.write_str(#join)?;
+                    }
+                    ::std::fmt::Display::fmt(field, f)?;
+                }
+                ::std::result::Result::Ok(())
+            }
+        }
+    }
+}
+
+fn display_generator() -> Generator {
+    Generator {
+        named_body: display_body,
+        unnamed_body: display_body,
+        enum_body: |_input, _generics, _inner| -> TokenStream2 {
+            darling::Error::unsupported_shape("expected a struct").write_errors()
+        },
+
+        named_field: |input, ident: &Ident, _field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#ident });
+            quote! { &&#this, }
+        },
+
+        unnamed_field: |input, index, _field| -> TokenStream2 {
+            let this = field_place(input, quote! { self.#index });
+            quote! { &&#this, }
+        },
+
+        enum_fields: |_variant| -> TokenStream2 { TokenStream2::new() },
+
+        field_bound: |_field| Ok(None),
+        unconditional: |_ty| false,
+        scoped_types: |_input| None,
+        scoped_predicates: |_input| Ok(None),
+        select_variants: |variants| Ok(variants.iter().collect()),
+        select_fields: |input| Ok(struct_fields(input).iter().collect()),
+    }
+}
+
+#[cfg(feature = "wrapper")]
+fn expand_wrapper(input: &BoundedDerive) -> darling::Result<TokenStream2> {
+    let field = match struct_fields(input) {
+        [field] => field,
+        _ => {
+            return Err(darling::Error::unsupported_shape(
+                "expected a struct with a single field",
+            ))
+        }
+    };
+
+    let member = match field.ident {
+        Some(ref ident) => quote! { #ident },
+        None => quote! { 0 },
+    };
+    let ty = &field.ty;
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let tokens = quote! {
+        impl #impl_generics #name #ty_generics #where_clause {
+            /// Consume the wrapper, returning the wrapped value
+            pub fn into_inner(self) -> #ty {
+                self.#member
+            }
+
+            /// Borrow the wrapped value
+            pub fn as_inner(&self) -> &#ty {
+                &self.#member
+            }
+        }
+    };
+
+    Ok(rename_crates(input, tokens))
+}
+
+fn expand_from_str(input: &BoundedDerive) -> darling::Result<TokenStream2> {
+    let variants = match input.data {
+        darling::ast::Data::Enum(ref variants) => variants,
+        darling::ast::Data::Struct(_) => {
+            return Err(darling::Error::unsupported_shape(
+                "expected an enum with fieldless variants",
+            ))
+        }
+    };
+
+    let mut errors = darling::Error::accumulator();
+    let arms = variants
+        .iter()
+        .filter_map(|variant| {
+            if !matches!(variant.fields, Fields::Unit) {
+                errors.push(
+                    darling::Error::custom("FromStr requires fieldless variants")
+                        .with_span(&variant.fields),
+                );
+                return None;
+            }
+
+            let variant_ident = &variant.ident;
+            let s = variant_ident.to_string();
+            Some(quote! { #s => ::std::result::Result::Ok(Self::#variant_ident), })
+        })
+        .collect::<Vec<_>>();
+    errors.finish()?;
+
+    let name = &input.ident;
+    let unknown = format!("unknown variant `{{}}` of `{name}`");
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    let tokens = quote! {
+        #[automatically_derived]
+        #[allow(unused_qualifications)]
+        impl #impl_generics ::std::str::FromStr for #name #ty_generics #where_clause {
+            type Err = ::std::string::String;
+
+            fn from_str(s: &str) -> ::std::result::Result<Self, Self::Err> {
+                match s {
+                    #(#arms)*
+                    _ => ::std::result::Result::Err(::std::format!(#unknown, s)),
+                }
+            }
+        }
+    };
+
+    Ok(rename_crates(input, tokens))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn expand_with(generator: Generator, bound: TokenStream2, input: DeriveInput) -> String {
+        let derive = BoundedDerive::from_derive_input(&input).unwrap();
+
+        expand(&derive, generator, bound).unwrap().to_string()
+    }
+
+    #[test]
+    fn struct_expansion() {
+        let input: DeriveInput = parse_quote! {
+            #[bounded_to(T::A)]
+            struct Named<T: Trait> { a: T::A, b: u8 }
+        };
+
+        assert_eq!(
+            expand_with(
+                clone_generator(),
+                quote! { ::std::clone::Clone },
+                input.clone()
+            ),
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl<T> ::std::clone::Clone for Named<T>
+                where T: Trait, T::A: ::std::clone::Clone
+                {
+                    fn clone(&self) -> Self {
+                        Self {
+                            a: ::std::clone::Clone::clone(&self.a),
+                            b: ::std::clone::Clone::clone(&self.b),
+                        }
+                    }
+                    fn clone_from(&mut self, source: &Self) {
+                        ::std::clone::Clone::clone_from(&mut self.a, &source.a);
+                        ::std::clone::Clone::clone_from(&mut self.b, &source.b);
+                    }
+                }
+            }
+            .to_string()
+        );
+        assert_eq!(
+            expand_with(
+                default_generator(),
+                quote! { ::std::default::Default },
+                input
+            ),
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl<T> ::std::default::Default for Named<T>
+                where T: Trait, T::A: ::std::default::Default
+                {
+                    fn default() -> Self {
+                        Self {
+                            a: <T::A as ::std::default::Default>::default(),
+                            b: <u8 as ::std::default::Default>::default(),
+                        }
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn qualified_bound() {
+        let input: DeriveInput = parse_quote! {
+            #[bounded_to(<<T as Outer>::X as Inner>::Y)]
+            struct Deep<T: Outer> { y: <<T as Outer>::X as Inner>::Y }
+        };
+        let expected: syn::WhereClause = parse_quote! {
+            where <<T as Outer>::X as Inner>::Y: ::std::clone::Clone, T: Outer
+        };
+
+        let derive = BoundedDerive::from_derive_input(&input).unwrap();
+        let output = expand(&derive, clone_generator(), quote! { ::std::clone::Clone }).unwrap();
+        let item: syn::ItemImpl = syn::parse2(output).unwrap();
+
+        assert_eq!(
+            item.generics.where_clause.to_token_stream().to_string(),
+            expected.to_token_stream().to_string()
+        );
+    }
+
+    #[test]
+    fn tuple_expansion() {
+        let input: DeriveInput = parse_quote! {
+            struct Tuple<T>(T, u8);
+        };
+
+        assert_eq!(
+            expand_with(debug_generator(), quote! { ::std::fmt::Debug }, input),
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl<T> ::std::fmt::Debug for Tuple<T>
+                where T: ::std::fmt::Debug
+                {
+                    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        let mut builder = f.debug_tuple("Tuple");
+                        builder.field(& &self.0);
+                        builder.field(& &self.1);
+                        builder.finish()
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn enum_expansion() {
+        let input: DeriveInput = parse_quote! {
+            #[bounded_to(T::A)]
+            enum En<T: Trait> { A, B(T::A), C { c: T::A } }
+        };
+
+        assert_eq!(
+            expand_with(
+                partial_eq_generator(),
+                quote! { ::std::cmp::PartialEq },
+                input
+            ),
+            quote! {
+                #[automatically_derived]
+                #[allow(unused_qualifications)]
+                impl<T> ::std::cmp::PartialEq for En<T>
+                where T: Trait, T::A: ::std::cmp::PartialEq
+                {
+                    fn eq(&self, other: &Self) -> bool {
+                        match (self, other) {
+                            (Self::A, Self::A) => true,
+                            (Self::B(self_0,), Self::B(other_0,)) => ::std::cmp::PartialEq::eq(self_0, other_0),
+                            (Self::C { c: self_c, }, Self::C { c: other_c, }) => ::std::cmp::PartialEq::eq(self_c, other_c),
+                            _ => false,
+                        }
+                    }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn sorted_predicates() {
+        let mut generics: Generics = parse_quote! { <T: Trait, U> };
+        generics.where_clause = Some(parse_quote! {
+            where U: Clone, T::C: Clone, T::A: Clone, <T::D as Trait>::B: Clone
+        });
+
+        normalize_generics(quote! { Clone }, &mut generics, ::std::iter::empty());
+        sort_predicates(&mut generics);
+
+        let where_clause = generics.where_clause.unwrap();
+        let expected: syn::WhereClause = parse_quote! {
+            where <T::D as Trait>::B: Clone, T: Trait, T::A: Clone, T::C: Clone, U: Clone
+        };
+
+        assert_eq!(
+            where_clause.to_token_stream().to_string(),
+            expected.to_token_stream().to_string()
+        );
+    }
+}
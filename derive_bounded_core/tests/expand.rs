@@ -0,0 +1,149 @@
+use proc_macro2::TokenStream;
+use syn::{parse_quote, DeriveInput};
+
+/// Assert the where clause of the impl in `tokens`
+fn assert_where(tokens: TokenStream, expected: syn::WhereClause) {
+    let item: syn::ItemImpl = syn::parse2(tokens).unwrap();
+    assert_eq!(
+        quote::ToTokens::to_token_stream(&item.generics.where_clause).to_string(),
+        quote::ToTokens::to_token_stream(&expected).to_string()
+    );
+}
+
+#[test]
+fn derive_clone() {
+    let input: DeriveInput = parse_quote! {
+        #[bounded_to(T::A)]
+        struct A<T: Trait> {
+            a: T::A,
+        }
+    };
+
+    assert_where(
+        derive_bounded_core::derive_clone(input).unwrap(),
+        parse_quote! { where T: Trait, T::A: ::std::clone::Clone },
+    );
+}
+
+#[cfg(feature = "wrapper")]
+#[test]
+fn derive_error() {
+    let input: DeriveInput = parse_quote! {
+        struct Pair(u8, u8);
+    };
+
+    let err = derive_bounded_core::derive_wrapper(input).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Unsupported shape `expected a struct with a single field`"
+    );
+}
+
+#[test]
+fn clone_copy() {
+    let input: DeriveInput = parse_quote! {
+        #[bounded(clone_copy)]
+        struct A<'a> {
+            a: u8,
+            b: &'a str,
+        }
+    };
+
+    let item: syn::ItemImpl =
+        syn::parse2(derive_bounded_core::derive_clone(input).unwrap()).unwrap();
+    let expected: syn::ImplItem = parse_quote! {
+        #[inline]
+        fn clone(&self) -> Self {
+            *self
+        }
+    };
+
+    assert_eq!(item.items.len(), 1);
+    assert_eq!(
+        quote::ToTokens::to_token_stream(&item.items[0]).to_string(),
+        quote::ToTokens::to_token_stream(&expected).to_string()
+    );
+}
+
+#[test]
+fn grouped_types() {
+    let input: DeriveInput = parse_quote! {
+        #[bounded_to((T::A, T::B), (U::C), ((T::A, U::C)))]
+        struct A<T: Trait, U: Other> {
+            a: T::A,
+            b: T::B,
+            c: U::C,
+            pair: (T::A, U::C),
+        }
+    };
+
+    assert_where(
+        derive_bounded_core::derive_clone(input).unwrap(),
+        parse_quote! {
+            where
+                (T::A, U::C): ::std::clone::Clone,
+                T: Trait,
+                T::A: ::std::clone::Clone,
+                T::B: ::std::clone::Clone,
+                U: Other,
+                U::C: ::std::clone::Clone
+        },
+    );
+}
+
+#[test]
+fn crate_paths() {
+    let input: DeriveInput = parse_quote! {
+        #[bounded_to(T::A, T::A: ::serde::Serialize)]
+        #[bounded(crate_paths(std = "::core", serde = "fw::serde"))]
+        struct A<T: Trait> {
+            a: T::A,
+            b: std::vec::Vec<u8>,
+        }
+    };
+
+    let tokens = derive_bounded_core::derive_clone(input).unwrap();
+    assert_where(
+        tokens.clone(),
+        parse_quote! { where T: Trait, T::A: fw::serde::Serialize, T::A: ::core::clone::Clone },
+    );
+    let tokens = tokens.to_string();
+    assert!(!tokens.contains(":: std"));
+    assert!(tokens.contains(":: core :: clone :: Clone for A"));
+}
+
+#[test]
+fn crate_paths_after_keywords() {
+    let input: DeriveInput = parse_quote! {
+        #[bounded_to(T::A)]
+        #[bounded(crate_paths(std = "::core"))]
+        #[display(join = ", ")]
+        struct A<T: Trait> {
+            a: T::A,
+            b: u8,
+        }
+    };
+    let fieldless: DeriveInput = parse_quote! {
+        #[bounded(crate_paths(std = "::core"))]
+        #[debug(with_discriminant)]
+        enum B {
+            C,
+            D,
+        }
+    };
+
+    let derives = [
+        derive_bounded_core::derive_debug(input.clone()),
+        derive_bounded_core::derive_default(input.clone()),
+        derive_bounded_core::derive_display(input.clone()),
+        derive_bounded_core::derive_partial_eq(input.clone()),
+        derive_bounded_core::derive_ord(input.clone()),
+        derive_bounded_core::derive_hash(input),
+        derive_bounded_core::derive_debug(fieldless.clone()),
+        derive_bounded_core::derive_from_str(fieldless),
+    ];
+    for tokens in derives {
+        let tokens = tokens.unwrap().to_string();
+        assert!(!tokens.contains(":: std"), "{}", tokens);
+    }
+}
@@ -25,630 +25,364 @@
 //!
 //! The auto-generated impl for [Clone][std::clone::Clone] will have a where clause with `T::B: Clone` instead of `T: Clone`.
 //!
+//! The types can also be listed as `#[bounded_to(types(T::B, T::C))]` or grouped in
+//! parentheses as `#[bounded_to((T::B, T::C), (U::D))]`: the groups are flattened, so a tuple
+//! type must be wrapped in a group of its own, e.g. `#[bounded_to(((T::B, U::D)))]`. Before the
+//! groups were flattened, `#[bounded_to((T::B, U::D))]` bounded the tuple type. An empty group,
+//! as `()` or `types()`, is rejected.
+//!
+//! An item of `bounded_to` can also be a whole predicate, e.g. `#[bounded_to(T::B: Send + Sync)]`,
+//! added as it is next to the bounds of the derived trait.
+//!
+//! `bounded_to` can be wrapped in `cfg_attr`, e.g. `#[cfg_attr(feature = "x", bounded_to(T::B))]`:
+//! the compiler expands it before the derive runs, so the bound applies only when the predicate
+//! holds.
+//!
+//! Type parameters used only by marker types such as [PhantomData][std::marker::PhantomData]
+//! are not bounded, other markers can be listed with `#[bounded(markers(MyMarker))]`.
+//!
+//! With `#[bounded(strict)]` only the `bounded_to` types and the bounds written on the item are
+//! used, the type parameters of the fields are never bounded on their own.
+//!
+//! Listing a type as a marker also helps with type-level lengths, as in `generic-array`: for a
+//! field `GenericArray<T::A, N>`, `#[bounded_to(T::A)] #[bounded(markers(GenericArray))]`
+//! bounds the items without bounding the length `N`.
+//!
+//! Structs ending with an unsized field, e.g. `[T::A]`, can derive the traits not requiring
+//! `Sized`, such as `Debug` and `PartialEq`, but not `Clone` nor `Default`.
+//!
+//! `Self` cannot be used in `bounded_to`, bounds on `Self` are added to every derived impl with
+//! `#[bounded(where_self = "Default + Send")]`.
+//!
+//! Importing `derive_bounded::Clone` replaces the `Clone` derive macro of the prelude, the
+//! trait lives in another namespace and `T: Clone` still names it. The derives are also
+//! available with a `Bounded` suffix, e.g. `#[derive(CloneBounded, DebugBounded)]`, to keep the
+//! standard derives usable in the same module.
+//!
+//! The generated code names the standard library as `::std`, the absolute paths rooted at a
+//! crate can be rewritten with `#[bounded(crate_paths(std = "::core", serde = "fw::serde"))]`,
+//! e.g. if `serde` is only available as a re-export. It applies to the bounds written in
+//! `bounded_to` as well, and the replacing paths must provide the same items.
+//!
+//! The fields of `#[repr(packed)]` structs are copied instead of borrowed, as they may be
+//! unaligned, so they must be `Copy`: a `Copy` bound is added for each of them.
+//!
 //! As this version there are few known limitations:
 //!
 //! - The macro does not auto-generate the where clause for associated traits, e.g. `A` in the
-//! example needs the `bounded_to` attribute
+//!   example needs the `bounded_to` attribute
+//! - Type aliases are not resolved, list the alias itself in `bounded_to`, e.g.
+//!   `#[bounded_to(Alias<T>)]` for a field `Alias<T>` standing for `<T as Trait>::B`
 //!
 //! Later versions will address those.
 //!
 
-use std::ops::Not;
-
-use darling::ast::Style;
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenStream as TokenStream2};
-use quote::{format_ident, quote};
-use syn::parse::{Parse, ParseStream};
-use syn::punctuated::Punctuated;
-use syn::token::{self, Comma};
-use syn::{
-    parenthesized, parse_macro_input, parse_quote, DeriveInput, Fields, Generics, Ident,
-    PredicateType, TypePath,
-};
-
-use darling::usage::{CollectTypeParams, GenericsExt, Purpose};
-use darling::FromDeriveInput;
-
-#[derive(std::fmt::Debug, FromDeriveInput)]
-#[darling(forward_attrs(bounded_to))]
-struct BoundedDerive {
-    ident: syn::Ident,
-    generics: syn::Generics,
-    data: darling::ast::Data<syn::Variant, syn::Field>,
-    attrs: Vec<syn::Attribute>,
-    //types: BoundedTypes,
-}
-
-struct BoundedAttr {
-    _paren_token: token::Paren,
-    types: Punctuated<syn::Type, Comma>,
-}
-
-impl Parse for BoundedAttr {
-    fn parse(input: ParseStream) -> Result<Self, syn::Error> {
-        let content;
-        let parent_token = parenthesized!(content in input);
-        Ok(BoundedAttr {
-            _paren_token: parent_token,
-            types: content.parse_terminated(syn::Type::parse)?,
-        })
-    }
-}
-
-fn normalize_generics<'a>(
-    bound: TokenStream2,
-    generics: &mut Generics,
-    types: impl Iterator<Item = &'a syn::Type>,
-) {
-    let bounds = generics
-        .type_params_mut()
-        .filter_map(|par| {
-            par.bounds.is_empty().not().then_some({
-                let bounds = std::mem::take(&mut par.bounds);
+use proc_macro2::TokenStream as TokenStream2;
+use syn::{parse_macro_input, DeriveInput};
 
-                let path: syn::Path = par.ident.clone().into();
-                let path = TypePath { qself: None, path };
-
-                PredicateType {
-                    lifetimes: None,
-                    bounded_ty: path.into(),
-                    colon_token: Default::default(),
-                    bounds,
-                }
-            })
-        })
-        .collect::<Vec<_>>();
-
-    let where_clause = generics.make_where_clause();
-    for bound in bounds {
-        where_clause.predicates.push(bound.into());
-    }
-
-    for ty in types {
-        let pred: syn::WherePredicate = parse_quote! { #ty: #bound };
-
-        where_clause.predicates.push(pred);
-    }
-}
-
-struct Generator {
-    named_body: fn(name: &Ident, generics: Generics, inner: TokenStream2) -> TokenStream2,
-    unnamed_body: fn(name: &Ident, generics: Generics, inner: TokenStream2) -> TokenStream2,
-    enum_body: fn(name: &Ident, generics: Generics, inner: TokenStream2) -> TokenStream2,
-
-    named_field: fn(field: &Ident) -> TokenStream2,
-    unnamed_field: fn(index: syn::Index) -> TokenStream2,
-    enum_fields: fn(variant: &syn::Variant) -> TokenStream2,
-}
-
-fn variant_fields(prefix: &Ident, fields: &syn::Fields) -> Vec<Ident> {
-    match fields {
-        Fields::Named(fields) => fields
-            .named
-            .iter()
-            .map(|f| {
-                let field = f.ident.as_ref().unwrap();
-                format_ident!("{prefix}_{field}")
-            })
-            .collect(),
-        Fields::Unnamed(fields) => fields
-            .unnamed
-            .iter()
-            .enumerate()
-            .map(|(i, _)| format_ident!("{prefix}_{i}"))
-            .collect(),
-        Fields::Unit => Vec::new(),
-    }
-}
-
-fn unpack_struct(var: &Ident, fields: &syn::Fields) -> TokenStream2 {
-    match fields {
-        Fields::Named(named) => {
-            let args = named
-                .named
-                .iter()
-                .map(|f| f.ident.as_ref().unwrap())
-                .zip(variant_fields(var, fields))
-                .map(|(v, f)| quote! { #v: #f });
-            quote! {
-                { #(#args, )* }
-            }
-        }
-        Fields::Unnamed(_) => {
-            let args = variant_fields(var, fields);
-            quote! {
-                ( #(#args, )* )
-            }
-        }
-        Fields::Unit => TokenStream2::new(),
-    }
-}
-
-fn common_bounded(items: TokenStream, generator: Generator, bound: TokenStream2) -> TokenStream {
+fn derive(items: TokenStream, expand: fn(DeriveInput) -> syn::Result<TokenStream2>) -> TokenStream {
     let input: DeriveInput = parse_macro_input!(items);
-    let default = match BoundedDerive::from_derive_input(&input) {
-        Ok(val) => val,
-        Err(err) => {
-            return err.write_errors().into();
-        }
-    };
-
-    let type_params = default.generics.declared_type_params();
-    let mut generics = default.generics.clone();
-
-    let mut types = Vec::new();
-    for attr in default.attrs.iter() {
-        match syn::parse2::<BoundedAttr>(attr.tokens.clone()) {
-            Ok(ba) => types.extend(ba.types.into_iter()),
-            Err(_) => {
-                return darling::Error::unsupported_format("expected bounded_to(...)")
-                    .write_errors()
-                    .into();
-            }
-        }
-    }
-
-    match default.data {
-        darling::ast::Data::Struct(ref fields) => {
-            let type_params_in_body = fields
-                .iter()
-                .collect_type_params(&Purpose::BoundImpl.into(), &type_params);
-
-            let type_params_in_attrs =
-                types.collect_type_params(&Purpose::BoundImpl.into(), &type_params);
-
-            let leftovers = type_params_in_body
-                .difference(&type_params_in_attrs)
-                .map(|&ident| {
-                    let path = syn::Path::from(ident.clone());
-                    let path = TypePath { qself: None, path };
-                    syn::Type::from(path)
-                })
-                .collect::<Vec<_>>();
-
-            normalize_generics(bound, &mut generics, types.iter().chain(leftovers.iter()));
-
-            match fields.style {
-                Style::Struct => {
-                    // SAFETY: Struct style struct has always fields
-                    let inner = TokenStream2::from_iter(
-                        fields
-                            .fields
-                            .iter()
-                            .map(|f| (generator.named_field)(f.ident.as_ref().unwrap())),
-                    );
-                    (generator.named_body)(&default.ident, generics, inner).into()
-                }
-                Style::Tuple => {
-                    let inner = TokenStream2::from_iter(
-                        fields
-                            .fields
-                            .iter()
-                            .enumerate()
-                            .map(|(i, _f)| (generator.unnamed_field)(syn::Index::from(i))),
-                    );
-                    (generator.unnamed_body)(&default.ident, generics, inner).into()
-                }
-                _ => todo!(),
-            }
-        }
-        darling::ast::Data::Enum(ref variants) => {
-            let type_params_in_body = variants
-                .iter()
-                .collect_type_params(&Purpose::BoundImpl.into(), &type_params);
 
-            let type_params_in_attrs =
-                types.collect_type_params(&Purpose::BoundImpl.into(), &type_params);
-
-            let leftovers = type_params_in_body
-                .difference(&type_params_in_attrs)
-                .map(|&ident| {
-                    let path = syn::Path::from(ident.clone());
-                    let path = TypePath { qself: None, path };
-                    syn::Type::from(path)
-                })
-                .collect::<Vec<_>>();
-
-            normalize_generics(bound, &mut generics, types.iter().chain(leftovers.iter()));
-
-            let inner = TokenStream2::from_iter(
-                variants
-                    .iter()
-                    .map(|variant| (generator.enum_fields)(variant)),
-            );
-
-            (generator.enum_body)(&default.ident, generics, inner).into()
-        }
-    }
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
 /// Derive [Default][std::default::Default]
 ///
 /// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
-#[proc_macro_derive(Default, attributes(bounded_to, default))]
+///
+/// Arrays implement `Default` only up to 32 elements, so a field such as `[u8; N]` with a
+/// const generic `N` cannot be defaulted, even if `N` has a default value.
+///
+/// On enums, mark the variant to construct with `#[default]`: only its fields are bounded.
+///
+/// A field can be initialized with a literal as `#[default = 42]`, with an expression as
+/// `#[default(value = "Vec::with_capacity(8)")]` or by calling a function as
+/// `#[default(with = "path")]`. Such fields are not bounded and only one of the forms can be
+/// used on a field.
+#[proc_macro_derive(Default, attributes(bounded_to, bounded, default))]
 pub fn default_bounded(items: TokenStream) -> TokenStream {
-    let struct_struct = Generator {
-        named_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::default::Default for #name #ty_generics #where_clause {
-                    fn default() -> Self {
-                        Self {
-                            #inner
-                        }
-                    }
-                }
-            }
-        },
-
-        unnamed_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::default::Default for #name #ty_generics #where_clause {
-                    fn default() -> Self {
-                        Self(
-                            #inner
-                        )
-                    }
-                }
-            }
-        },
-
-        enum_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::default::Default for #name #ty_generics #where_clause {
-                    fn default() -> Self {
-                        Self::#inner
-                    }
-                }
-            }
-        },
-
-        named_field: |field: &Ident| -> TokenStream2 {
-            quote! { #field: std::default::Default::default(), }
-        },
-
-        unnamed_field: |_index| -> TokenStream2 {
-            quote! { std::default::Default::default(), }
-        },
-
-        enum_fields: |_variant| -> TokenStream2 {
-            darling::Error::unsupported_shape("Enum default is not supported").write_errors()
-        },
-    };
-
-    let bound = quote! { std::default::Default };
-
-    common_bounded(items, struct_struct, bound)
+    derive(items, derive_bounded_core::derive_default)
 }
 
 /// Derive [Clone][std::clone::Clone]
 ///
 /// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
-#[proc_macro_derive(Clone, attributes(bounded_to))]
+///
+/// Structs and enums also get a field-wise `clone_from`, reusing the allocations of the
+/// destination. An enum value holding a different variant than the source is replaced by a clone.
+///
+/// Shared references, `Rc`, `Arc` and their `Weak` pointers do not bound the type parameters
+/// they point to, also when nested as in `Option<&T::A>`.
+///
+/// Fields owning a trait object, e.g. `Box<dyn Trait>` or a boxed closure, need
+/// `#[clone(with = "path")]`, where `path` is a `fn(&Field) -> Field` used in place of
+/// `Clone::clone`.
+///
+/// A struct field can also be cloned by an expression as `#[clone(via = "self.a.custom_clone()")]`,
+/// such structs use the default `clone_from`.
+///
+/// Items marked with `#[bounded(boxed_clone)]` also get an inherent
+/// `fn boxed_clone(&self) -> Box<Self>`, handy to implement cloning for trait objects.
+///
+/// `Copy` items marked with `#[bounded(clone_copy)]` are cloned as `*self` if they have no type
+/// parameter and their fields are all primitives, shared references or arrays and tuples of
+/// those. Otherwise they are cloned field by field.
+#[proc_macro_derive(Clone, attributes(bounded_to, bounded, clone))]
 pub fn clone_bounded(items: TokenStream) -> TokenStream {
-    let struct_struct = Generator {
-        named_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::clone::Clone for #name #ty_generics #where_clause {
-                    fn clone(&self) -> Self {
-                        Self {
-                            #inner
-                        }
-                    }
-                }
-            }
-        },
-
-        unnamed_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::clone::Clone for #name #ty_generics #where_clause {
-                    fn clone(&self) -> Self {
-                        Self (
-                            #inner
-                        )
-                    }
-                }
-            }
-        },
-
-        enum_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::clone::Clone for #name #ty_generics #where_clause {
-                    fn clone(&self) -> Self {
-                        match self {
-                            #inner
-                        }
-                    }
-                }
-            }
-        },
-
-        named_field: |field: &Ident| -> TokenStream2 {
-            quote! { #field: self.#field.clone(), }
-        },
-
-        unnamed_field: |index| -> TokenStream2 {
-            quote! { self.#index.clone(), }
-        },
-
-        enum_fields: |variant| -> TokenStream2 {
-            let self_ident = Ident::new("self", Span::call_site());
-            let self_fields = variant_fields(&self_ident, &variant.fields);
-            let match_arm = unpack_struct(&self_ident, &variant.fields);
-            let variant_ident = &variant.ident;
-
-            let inner = match variant.fields {
-                Fields::Named(ref named) => {
-                    let inner = TokenStream2::from_iter(
-                        named.named.iter().zip(self_fields.iter()).map(|(f, s)| {
-                            let f = f.ident.as_ref().unwrap();
-                            quote! { #f: #s.clone(), }
-                        }),
-                    );
-                    quote! {
-                        Self:: #variant_ident { #inner }
-                    }
-                }
-                Fields::Unnamed(_) => {
-                    let inner = TokenStream2::from_iter(self_fields.iter().map(|s| {
-                        quote! { #s.clone(), }
-                    }));
-                    quote! {
-                        Self:: #variant_ident ( #inner )
-                    }
-                }
-                Fields::Unit => quote! { Self:: #variant_ident },
-            };
-
-            quote! { Self:: #variant_ident #match_arm => #inner, }
-        },
-    };
-
-    let bound = quote! { std::clone::Clone };
-
-    common_bounded(items, struct_struct, bound)
+    derive(items, derive_bounded_core::derive_clone)
 }
 
 /// Derive [Debug][std::fmt::Debug]
 ///
 /// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
-#[proc_macro_derive(Debug, attributes(bounded_to))]
+///
+/// Fields marked with `#[debug(skip_if = "path")]` are omitted when `path(&field)` returns
+/// `true`, tuple fields are replaced by `..` to keep the position of the others.
+///
+/// Collections marked with `#[debug(max_len = N)]` print their first `N` items, followed by
+/// `..` if they hold more.
+///
+/// Fieldless enums marked with `#[debug(with_discriminant)]` print each variant along with its
+/// discriminant value, e.g. `Red (1)`, as the integer type of `#[repr(...)]`, `isize` by default.
+///
+/// Items marked with `#[bounded(debug_pretty)]` are always pretty printed, as with `{:#?}`.
+///
+/// `#[debug(bound = "T: Debug, T::A: Debug")]` replaces both the inferred and the `bounded_to`
+/// bounds of the `Debug` impl alone.
+///
+/// Tuple structs marked with `#[bounded(debug_tuple_as_struct)]` are printed as structs with
+/// the field indices as names, e.g. `Pair { 0: 1, 1: 2 }`.
+#[proc_macro_derive(Debug, attributes(bounded_to, bounded, debug))]
 pub fn debug_bounded(items: TokenStream) -> TokenStream {
-    let struct_struct = Generator {
-        named_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            let s = name.to_string();
-            quote! {
-                impl #impl_generics std::fmt::Debug for #name #ty_generics #where_clause {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.debug_struct(#s)
-                        #inner
-                        .finish()
-                    }
-                }
-            }
-        },
-
-        unnamed_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            let s = name.to_string();
-            quote! {
-                impl #impl_generics std::fmt::Debug for #name #ty_generics #where_clause {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        f.debug_tuple(#s)
-                        #inner
-                        .finish()
-                    }
-                }
-            }
-        },
-
-        enum_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::fmt::Debug for #name #ty_generics #where_clause {
-                    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                        match self {
-                            #inner
-                        }
-                    }
-                }
-            }
-        },
-
-        named_field: |field: &Ident| -> TokenStream2 {
-            let s = field.to_string();
-            quote! { .field(#s, &self.#field) }
-        },
-
-        unnamed_field: |index| -> TokenStream2 {
-            quote! { .field(&self.#index) }
-        },
-        enum_fields: |variant| -> TokenStream2 {
-            let self_ident = Ident::new("self", Span::call_site());
-            let self_fields = variant_fields(&self_ident, &variant.fields);
-            let match_arm = unpack_struct(&self_ident, &variant.fields);
-            let variant_ident = &variant.ident;
-            let s = variant_ident.to_string();
-            let inner = match variant.fields {
-                Fields::Named(ref named) => {
-                    let inner = TokenStream2::from_iter(
-                        named.named.iter().zip(self_fields.iter()).map(|(s, f)| {
-                            let s = s.ident.as_ref().unwrap().to_string();
-                            quote! { .field(#s, #f) }
-                        }),
-                    );
-                    quote! {
-                        f.debug_struct(#s)
-                        #inner
-                        .finish()
-                    }
-                }
-                Fields::Unnamed(_) => {
-                    let inner = TokenStream2::from_iter(self_fields.iter().map(|s| {
-                        quote! { .field(#s) }
-                    }));
-                    quote! {
-                          f.debug_tuple(#s)
-                          #inner
-                          .finish()
-                    }
-                }
-                Fields::Unit => quote! { f.write_str(#s) },
-            };
-
-            quote! { Self:: #variant_ident #match_arm => #inner, }
-        },
-    };
-
-    let bound = quote! { std::fmt::Debug };
+    derive(items, derive_bounded_core::derive_debug)
+}
 
-    common_bounded(items, struct_struct, bound)
+/// Derive [Display][std::fmt::Display] for structs
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// The struct must be marked with `#[display(join = ", ")]`: the fields are displayed in
+/// declaration order, separated by the given string.
+#[proc_macro_derive(Display, attributes(bounded_to, bounded, display))]
+pub fn display_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_display)
 }
 
 /// Derive [PartialEq][std::cmp::PartialEq]
 ///
 /// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
-#[proc_macro_derive(PartialEq, attributes(bounded_to))]
+///
+/// Collections marked with `#[partial_eq(unordered)]` compare equal if they hold the same items
+/// regardless of the order, which takes a time quadratic in their length.
+///
+/// Float fields marked with `#[partial_eq(total_float)]` compare with `total_cmp`, so `NaN`
+/// equals itself while `0.0` and `-0.0` differ.
+///
+/// A single-field struct marked with `#[partial_eq(with = "[T::A], Vec<T::A>")]` can also be
+/// compared with the listed types, delegating to its field.
+///
+/// Structs marked with `#[partial_eq(approx)]` also get an inherent
+/// `fn approx_eq(&self, other: &Self, epsilon: f64) -> bool`, comparing their `f32` and `f64`
+/// fields within the given tolerance and the others as `eq` does.
+#[proc_macro_derive(PartialEq, attributes(bounded_to, bounded, partial_eq))]
 pub fn partial_eq_bounded(items: TokenStream) -> TokenStream {
-    let struct_struct = Generator {
-        named_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::cmp::PartialEq for #name #ty_generics #where_clause {
-                    fn eq(&self, other: &Self) -> bool {
-                        true
-                        #inner
-                    }
-                }
-            }
-        },
-
-        unnamed_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::cmp::PartialEq for #name #ty_generics #where_clause {
-                    fn eq(&self, other: &Self) -> bool {
-                        true
-                        #inner
-                    }
-                }
-            }
-        },
-
-        enum_body: |name: &Ident, generics: Generics, inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
-
-            quote! {
-                impl #impl_generics std::cmp::PartialEq for #name #ty_generics #where_clause {
-                    fn eq(&self, other: &Self) -> bool {
-                        match (self, other) {
-                            #inner
-                            _ => false,
-                        }
-                    }
-                }
-            }
-        },
+    derive(items, derive_bounded_core::derive_partial_eq)
+}
 
-        named_field: |field: &Ident| -> TokenStream2 {
-            quote! { && other.#field == self.#field }
-        },
+/// Derive [Eq][std::cmp::Eq]
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// Use `#[bounded(eq_bound(T, A::B))]` if `Eq` needs different bounds than the other derives.
+#[proc_macro_derive(Eq, attributes(bounded_to, bounded))]
+pub fn eq_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_eq)
+}
 
-        unnamed_field: |index| -> TokenStream2 {
-            quote! { && other.#index == self.#index }
-        },
+/// Derive [Hash][std::hash::Hash]
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// Enum variants are hashed by their declaration index, followed by their fields.
+///
+/// The field options are the ones of `HashEq`, pair `#[hash(unordered)]` and `#[hash(with)]`
+/// with a matching `PartialEq` so that equal values hash the same, or derive `HashEq` instead.
+#[proc_macro_derive(Hash, attributes(bounded_to, bounded, hash))]
+pub fn hash_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_hash)
+}
 
-        enum_fields: |variant| -> TokenStream2 {
-            let self_ident = Ident::new("self", Span::call_site());
-            let self_fields = variant_fields(&self_ident, &variant.fields);
-            let other_ident = Ident::new("other", Span::call_site());
-            let other_fields = variant_fields(&other_ident, &variant.fields);
-            let self_match_arm = unpack_struct(&self_ident, &variant.fields);
-            let other_match_arm = unpack_struct(&other_ident, &variant.fields);
-            let variant_ident = &variant.ident;
-            let variant_ident = quote! { Self:: #variant_ident };
+/// Derive [PartialEq][std::cmp::PartialEq], [Eq][std::cmp::Eq] and [Hash][std::hash::Hash] at once
+///
+/// The three impls are generated from the same attributes, so values comparing equal always
+/// hash the same.
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// Collections marked with `#[partial_eq(unordered)]` or `#[hash(unordered)]` are compared and
+/// hashed regardless of the order of their items. Comparing takes a quadratic time, hashing
+/// sums the hashes of the items.
+///
+/// Use `#[hash(with = "path")]` to hash a field with a `fn(&Field, &mut H)`, `H` being either
+/// a generic `Hasher` or `dyn Hasher`. The field is not bounded.
+///
+/// Structs marked with `#[hash(named)]` hash every field along with its name on its own and
+/// sum the results, so the hash does not change if the fields are reordered. It costs an extra
+/// hasher per field.
+///
+/// Enums marked with `#[hash(variant_name)]` hash the name of the variant instead of its
+/// declaration index, so the hash does not change if the variants are reordered. Hashing a
+/// string is slower than hashing an index.
+#[proc_macro_derive(HashEq, attributes(bounded_to, bounded, partial_eq, hash))]
+pub fn hash_eq_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_hash_eq)
+}
 
-            let match_arm =
-                quote! { (#variant_ident #self_match_arm, #variant_ident #other_match_arm) };
+/// Derive [PartialOrd][std::cmp::PartialOrd]
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// Fields are compared in declaration order, enum variants by their declaration index, even if
+/// they have explicit discriminants.
+/// Use `#[ord(with = "path")]` to compare a field with a `fn(&Field, &Field) -> Ordering`.
+///
+/// `#[bounded(partial_ord_only_fields(b, a))]` compares only the listed fields, in the listed
+/// order, and bounds only them. `PartialOrd` and `Ord` share it, so they stay consistent.
+#[proc_macro_derive(PartialOrd, attributes(bounded_to, bounded, ord))]
+pub fn partial_ord_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_partial_ord)
+}
 
-            // TODO replace with intersperse
-            let inner = TokenStream2::from_iter(other_fields.iter().zip(self_fields.iter()).map(
-                |(o, s)| {
-                    quote! { && #o == #s }
-                },
-            ));
+/// Derive [Ord][std::cmp::Ord]
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// Fields are compared in declaration order, enum variants by their declaration index, even if
+/// they have explicit discriminants.
+/// Use `#[ord(with = "path")]` to compare a field with a `fn(&Field, &Field) -> Ordering`.
+///
+/// `#[bounded(partial_ord_only_fields(b, a))]` compares only the listed fields, in the listed
+/// order, and bounds only them. `PartialOrd` and `Ord` share it, so they stay consistent.
+#[proc_macro_derive(Ord, attributes(bounded_to, bounded, ord))]
+pub fn ord_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_ord)
+}
 
-            quote! { #match_arm => true #inner, }
-        },
-    };
+/// Derive [PartialEq][std::cmp::PartialEq], [Eq][std::cmp::Eq], [Hash][std::hash::Hash],
+/// [PartialOrd][std::cmp::PartialOrd] and [Ord][std::cmp::Ord] at once
+///
+/// The five impls are generated from the same attributes, so they agree on the compared fields:
+/// `#[bounded(partial_ord_only_fields(b, a))]` restricts the equality and the hash as well.
+///
+/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
+///
+/// `#[hash(named)]`, `#[hash(variant_name)]` and `#[hash(with = "path")]` are accepted. The options
+/// comparing or hashing a field in a way the other impls cannot follow are rejected:
+/// `#[partial_eq(unordered)]`, `#[partial_eq(total_float)]`, `#[hash(unordered)]` and
+/// `#[ord(with = "path")]`, as well as `#[partial_eq(with = "...")]` and `#[partial_eq(approx)]`
+/// on the item.
+#[proc_macro_derive(TotalOrd, attributes(bounded_to, bounded, partial_eq, hash, ord))]
+pub fn total_ord_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_total_ord)
+}
 
-    let bound = quote! { std::cmp::PartialEq };
+/// Derive several traits sharing one list of bounds
+///
+/// `#[bounded(derive(Clone, Debug), to(T::A))]` stands for `#[derive(Clone, Debug)]`, using the
+/// derives of this crate, and `#[bounded_to(T::A)]`.
+///
+/// The derives use `#[bounded(...)]` for their own options as well, so refer to the attribute
+/// as `#[derive_bounded::bounded(...)]` instead of importing it when they are needed.
+#[proc_macro_attribute]
+pub fn bounded(args: TokenStream, item: TokenStream) -> TokenStream {
+    derive_bounded_core::bounded(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    common_bounded(items, struct_struct, bound)
+/// Qualified form of the `bounded_to` attribute, as `#[derive_bounded::bounded_to(T::A)]`
+///
+/// It can be placed before or after the derives and does nothing on its own.
+#[proc_macro_attribute]
+pub fn bounded_to(args: TokenStream, item: TokenStream) -> TokenStream {
+    derive_bounded_core::bounded_to(args.into(), item.into())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-/// Derive [Eq][std::cmp::Eq]
+/// Derive [FromStr][std::str::FromStr] for enums with fieldless variants
 ///
-/// Use the attribute `#[bounded_to(T, A::B)] to to specify more precise bounds.
-#[proc_macro_derive(Eq, attributes(bounded_to))]
-pub fn eq_bounded(items: TokenStream) -> TokenStream {
-    let struct_struct = Generator {
-        named_body: |name: &Ident, generics: Generics, _inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+/// The string must match the name of a variant exactly, e.g. `"Red".parse()`, otherwise the
+/// error is a `String` describing it. No bound is added.
+#[proc_macro_derive(FromStr)]
+pub fn from_str_bounded(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_from_str)
+}
 
-            quote! {
-                impl #impl_generics std::cmp::Eq for #name #ty_generics #where_clause {}
-            }
-        },
+/// Derive the inherent `into_inner` and `as_inner` methods for single-field structs
+///
+/// No bound is added, the methods are available whatever the type parameters are.
+///
+/// Requires the `wrapper` feature.
+#[cfg(feature = "wrapper")]
+#[proc_macro_derive(Wrapper)]
+pub fn wrapper(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_wrapper)
+}
 
-        unnamed_body: |name: &Ident, generics: Generics, _inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+/// Same as [Default](derive@Default)
+#[proc_macro_derive(DefaultBounded, attributes(bounded_to, bounded, default))]
+pub fn default_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_default)
+}
 
-            quote! {
-                impl #impl_generics std::cmp::Eq for #name #ty_generics #where_clause {}
-            }
-        },
+/// Same as [Clone](derive@Clone)
+#[proc_macro_derive(CloneBounded, attributes(bounded_to, bounded, clone))]
+pub fn clone_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_clone)
+}
 
-        enum_body: |name: &Ident, generics: Generics, _inner| -> TokenStream2 {
-            let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+/// Same as [Debug](derive@Debug)
+#[proc_macro_derive(DebugBounded, attributes(bounded_to, bounded, debug))]
+pub fn debug_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_debug)
+}
 
-            quote! {
-                impl #impl_generics std::cmp::Eq for #name #ty_generics #where_clause {}
-            }
-        },
+/// Same as [PartialEq](derive@PartialEq)
+#[proc_macro_derive(PartialEqBounded, attributes(bounded_to, bounded, partial_eq))]
+pub fn partial_eq_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_partial_eq)
+}
 
-        named_field: |_field: &Ident| -> TokenStream2 {
-            quote! {}
-        },
+/// Same as [Eq](derive@Eq)
+#[proc_macro_derive(EqBounded, attributes(bounded_to, bounded))]
+pub fn eq_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_eq)
+}
 
-        unnamed_field: |_index| -> TokenStream2 {
-            quote! {}
-        },
+/// Same as [Hash](derive@Hash)
+#[proc_macro_derive(HashBounded, attributes(bounded_to, bounded, hash))]
+pub fn hash_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_hash)
+}
 
-        enum_fields: |_variant| -> TokenStream2 {
-            quote! {}
-        },
-    };
+/// Same as [Display](derive@Display)
+#[proc_macro_derive(DisplayBounded, attributes(bounded_to, bounded, display))]
+pub fn display_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_display)
+}
 
-    let bound = quote! { std::cmp::Eq };
+/// Same as [PartialOrd](derive@PartialOrd)
+#[proc_macro_derive(PartialOrdBounded, attributes(bounded_to, bounded, ord))]
+pub fn partial_ord_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_partial_ord)
+}
 
-    common_bounded(items, struct_struct, bound)
+/// Same as [Ord](derive@Ord)
+#[proc_macro_derive(OrdBounded, attributes(bounded_to, bounded, ord))]
+pub fn ord_bounded_alias(items: TokenStream) -> TokenStream {
+    derive(items, derive_bounded_core::derive_ord)
 }
@@ -0,0 +1,59 @@
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+use derive_bounded::{
+    CloneBounded, DebugBounded, DefaultBounded, DisplayBounded, EqBounded, HashBounded, OrdBounded,
+    PartialEqBounded, PartialOrdBounded,
+};
+
+trait Associate {
+    type A;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+#[derive(
+    CloneBounded,
+    DebugBounded,
+    DefaultBounded,
+    DisplayBounded,
+    PartialEqBounded,
+    EqBounded,
+    HashBounded,
+    PartialOrdBounded,
+    OrdBounded,
+)]
+#[bounded_to(T::A)]
+#[display(join = ", ")]
+struct Aliased<T: Associate> {
+    a: T::A,
+}
+
+/// Uses the std traits by name next to the aliases
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+struct Std(u8);
+
+fn duplicate<C: Clone>(c: &C) -> (C, C) {
+    (c.clone(), c.clone())
+}
+
+#[test]
+fn aliases() {
+    let a = Aliased::<Holder> { a: 1 };
+    let (b, c) = duplicate(&a);
+    assert_eq!(b, c);
+    assert!(Aliased::<Holder>::default() < a);
+    assert_eq!(a.cmp(&b), std::cmp::Ordering::Equal);
+    assert_eq!(format!("{:?}", a), "Aliased { a: 1 }");
+    assert_eq!(a.to_string(), "1");
+
+    let state = RandomState::new();
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+
+    let (s, t) = duplicate(&Std::default());
+    assert_eq!(s, t);
+}
@@ -0,0 +1,28 @@
+#![allow(dead_code)]
+use derive_bounded::Clone;
+
+trait Associate {
+    type A;
+    type B: Clone;
+}
+
+struct NotClone;
+
+impl Associate for NotClone {
+    type A = NotClone;
+    type B = u8;
+}
+
+// cfg_attr is expanded before the derive runs, the bound applies if the predicate holds
+#[derive(Clone)]
+#[cfg_attr(test, bounded_to(T::B))]
+#[cfg_attr(not(test), bounded_to(T::A))]
+struct Gated<T: Associate> {
+    b: T::B,
+}
+
+#[test]
+fn cfg_attr_bounded_to() {
+    let g = Gated::<NotClone> { b: 1 };
+    assert_eq!(g.clone().b, 1);
+}
@@ -0,0 +1,32 @@
+#![deny(clippy::nonminimal_bool, clippy::needless_bool)]
+use derive_bounded::PartialEq;
+
+#[derive(PartialEq)]
+struct Named {
+    a: u8,
+    b: u16,
+}
+
+#[derive(PartialEq)]
+struct Unnamed(u8, u16);
+
+#[derive(PartialEq)]
+struct Empty {}
+
+#[derive(PartialEq)]
+enum Shapes {
+    Unit,
+    One(u8),
+    Two { a: u8, b: u16 },
+}
+
+#[test]
+fn minimal_bool() {
+    assert!(Named { a: 1, b: 2 } == Named { a: 1, b: 2 });
+    assert!(Named { a: 1, b: 2 } != Named { a: 1, b: 3 });
+    assert!(Unnamed(1, 2) != Unnamed(2, 2));
+    assert!(Empty {} == Empty {});
+    assert!(Shapes::Unit == Shapes::Unit);
+    assert!(Shapes::One(1) != Shapes::Two { a: 1, b: 2 });
+    assert!(Shapes::Two { a: 1, b: 2 } == Shapes::Two { a: 1, b: 2 });
+}
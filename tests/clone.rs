@@ -1,3 +1,4 @@
+#![allow(dead_code)]
 use derive_bounded::Clone;
 
 trait Associate {
@@ -49,6 +50,67 @@ where
 #[derive(Clone, Debug)]
 struct Struct<A>(A);
 
+trait Container {
+    type Item;
+}
+
+struct NotClone;
+
+impl Container for NotClone {
+    type Item = String;
+}
+
+struct Wrapper<T: Container>(T::Item);
+
+impl<T: Container> std::clone::Clone for Wrapper<T>
+where
+    T::Item: Clone,
+{
+    fn clone(&self) -> Self {
+        Wrapper(self.0.clone())
+    }
+}
+
+#[derive(Clone, derive_bounded::Debug)]
+struct Buf<const N: usize = 32> {
+    data: [u8; N],
+}
+
+#[derive(Clone, derive_bounded::Debug)]
+struct Tagged<T = u8, const N: usize = 4>([T; N]);
+
+#[derive(Clone)]
+struct Bespoke<T: Container> {
+    #[clone(bound = "T::Item: Clone")]
+    w: Wrapper<T>,
+    n: u32,
+}
+
+trait Convert<X> {
+    type Out: Clone;
+}
+
+impl<X> Convert<X> for NotClone {
+    type Out = Vec<u8>;
+}
+
+#[derive(Clone)]
+#[bounded_to(<T as Convert<X>>::Out)]
+struct Converted<T: Convert<X>, X> {
+    out: <T as Convert<X>>::Out,
+    from: std::marker::PhantomData<(T, X)>,
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A)]
+struct Inner<T: Associate> {
+    items: Vec<T::A>,
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A, T::B)]
+struct Outer<T: Associate>(Inner<T>, T::B);
+
 #[test]
 fn clone() {
     let c2 = C2::<Holder, usize, usize> {
@@ -62,3 +124,641 @@ fn clone() {
     dbg!(&d);
     dbg!(&c2);
 }
+
+#[test]
+fn clone_field_bound() {
+    let b = Bespoke::<NotClone> {
+        w: Wrapper("bespoke".to_owned()),
+        n: 7,
+    };
+
+    let c = b.clone();
+
+    assert_eq!(c.w.0, "bespoke");
+    assert_eq!(c.n, 7);
+}
+
+#[test]
+fn clone_const_default() {
+    let b: Buf = Buf { data: [1; 32] };
+    let t: Tagged = Tagged([2; 4]);
+
+    assert_eq!(b.clone().data, b.data);
+    assert_eq!(format!("{:?}", t.clone()), "Tagged([2, 2, 2, 2])");
+}
+
+#[test]
+fn clone_from_reuses_allocations() {
+    let source = Outer::<Holder>(
+        Inner {
+            items: vec![1, 2, 3],
+        },
+        "source".to_owned(),
+    );
+    let mut dest = Outer::<Holder>(
+        Inner {
+            items: Vec::with_capacity(16),
+        },
+        String::with_capacity(16),
+    );
+
+    let items = dest.0.items.as_ptr();
+    let label = dest.1.as_ptr();
+
+    dest.clone_from(&source);
+
+    assert_eq!(dest.0.items, [1, 2, 3]);
+    assert_eq!(dest.1, "source");
+    assert_eq!(dest.0.items.as_ptr(), items);
+    assert_eq!(dest.1.as_ptr(), label);
+}
+
+#[test]
+fn clone_generic_trait_qualified() {
+    let c = Converted::<NotClone, NotClone> {
+        out: vec![1, 2],
+        from: std::marker::PhantomData,
+    };
+
+    assert_eq!(c.clone().out, [1, 2]);
+}
+
+trait Pointee {
+    type A;
+}
+
+impl Pointee for NotClone {
+    type A = NotClone;
+}
+
+#[derive(Clone)]
+struct Shared<T: Pointee> {
+    slice: std::rc::Rc<[T::A]>,
+    single: std::sync::Arc<T::A>,
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A)]
+struct Boxed<T: Associate> {
+    slice: Box<[T::A]>,
+}
+
+#[test]
+fn shared_slices() {
+    let s = Shared::<NotClone> {
+        slice: vec![NotClone, NotClone].into(),
+        single: std::sync::Arc::new(NotClone),
+    };
+    let c = s.clone();
+    assert!(std::rc::Rc::ptr_eq(&s.slice, &c.slice));
+    assert_eq!(std::sync::Arc::strong_count(&s.single), 2);
+
+    let b = Boxed::<Holder> {
+        slice: vec![1, 2, 3].into(),
+    };
+    assert_eq!(b.clone().slice, b.slice);
+}
+
+#[derive(Clone)]
+struct Weak<T: Pointee> {
+    local: std::rc::Weak<T::A>,
+    sync: std::sync::Weak<T::A>,
+}
+
+#[test]
+fn weak_pointers() {
+    let local = std::rc::Rc::new(NotClone);
+    let sync = std::sync::Arc::new(NotClone);
+    let w = Weak::<NotClone> {
+        local: std::rc::Rc::downgrade(&local),
+        sync: std::sync::Arc::downgrade(&sync),
+    };
+
+    let c = w.clone();
+    assert!(c.local.ptr_eq(&w.local));
+    assert_eq!(std::rc::Rc::weak_count(&local), 2);
+    assert_eq!(std::sync::Arc::weak_count(&sync), 2);
+}
+
+#[derive(Clone)]
+struct Pointers<T: Pointee> {
+    a: *const T::A,
+    b: *mut T,
+}
+
+#[test]
+fn raw_pointers() {
+    let a = NotClone;
+    let mut b = NotClone;
+    let p = Pointers::<NotClone> { a: &a, b: &mut b };
+    let c = p.clone();
+    assert_eq!(c.a, p.a);
+    assert_eq!(c.b, p.b);
+}
+
+trait Shape {
+    fn boxed(&self) -> Box<dyn Shape>;
+}
+
+struct Square;
+
+impl Shape for Square {
+    fn boxed(&self) -> Box<dyn Shape> {
+        Box::new(Square)
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn clone_shape(shape: &Box<dyn Shape>) -> Box<dyn Shape> {
+    shape.boxed()
+}
+
+#[derive(Clone)]
+#[bounded_to(T::B)]
+struct Drawing<T: Associate> {
+    name: T::B,
+    #[clone(with = "clone_shape")]
+    shape: Box<dyn Shape>,
+}
+
+#[derive(Clone)]
+enum Layer {
+    Shape(#[clone(with = "clone_shape")] Box<dyn Shape>),
+}
+
+#[test]
+fn clone_with() {
+    let mut d = Drawing::<Holder> {
+        name: "square".to_owned(),
+        shape: Box::new(Square),
+    };
+    let c = d.clone();
+    d.clone_from(&c);
+    assert_eq!(d.name, c.name);
+
+    let Layer::Shape(_) = Layer::Shape(Box::new(Square)).clone();
+}
+
+trait Factory: 'static {
+    type Output;
+
+    fn make() -> Self::Output;
+}
+
+impl Factory for NotClone {
+    type Output = NotClone;
+
+    fn make() -> NotClone {
+        NotClone
+    }
+}
+
+#[allow(clippy::borrowed_box)]
+fn rebuild<T: Factory>(_: &Box<dyn Fn() -> T::Output>) -> Box<dyn Fn() -> T::Output> {
+    Box::new(T::make)
+}
+
+#[derive(Clone)]
+struct Lazy<T: Factory> {
+    #[clone(with = "rebuild::<T>")]
+    make: Box<dyn Fn() -> T::Output>,
+}
+
+#[test]
+fn clone_closure_with() {
+    let lazy = Lazy::<NotClone> {
+        make: Box::new(|| NotClone),
+    };
+    let NotClone = (lazy.clone().make)();
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A)]
+enum Payload<T: Associate> {
+    Empty,
+    Items(Vec<T::A>),
+    Named { label: String },
+}
+
+#[test]
+fn enum_clone_from_reuses_allocations() {
+    let source = Payload::<Holder>::Items(vec![1, 2, 3]);
+    let mut dest = Payload::<Holder>::Items(Vec::with_capacity(16));
+    let Payload::Items(ref items) = dest else {
+        unreachable!()
+    };
+    let items = items.as_ptr();
+
+    dest.clone_from(&source);
+
+    let Payload::Items(ref cloned) = dest else {
+        unreachable!()
+    };
+    assert_eq!(cloned, &[1, 2, 3]);
+    assert_eq!(cloned.as_ptr(), items);
+
+    dest.clone_from(&Payload::Named {
+        label: "named".to_owned(),
+    });
+    assert!(matches!(dest, Payload::Named { ref label } if label == "named"));
+
+    dest.clone_from(&Payload::Empty);
+    assert!(matches!(dest, Payload::Empty));
+}
+
+#[derive(Clone)]
+struct Borrowed<'a, T: Pointee> {
+    maybe: Option<&'a T::A>,
+    all: Vec<&'a T>,
+    shared: Vec<std::rc::Rc<T::A>>,
+}
+
+#[test]
+fn option_reference() {
+    let a = NotClone;
+    let b = Borrowed::<NotClone> {
+        maybe: Some(&a),
+        all: vec![&a],
+        shared: vec![std::rc::Rc::new(NotClone)],
+    };
+    let c = b.clone();
+
+    assert!(std::ptr::eq(c.maybe.unwrap(), &a));
+    assert!(std::rc::Rc::ptr_eq(&b.shared[0], &c.shared[0]));
+}
+
+#[derive(Clone)]
+#[bounded_to(T::B, T::B: Send + Sync)]
+struct Job<T: Associate, U> {
+    tag: T::B,
+    payload: U,
+}
+
+fn send_clone<J: Clone + Send + Sync>(job: &J) -> J {
+    std::thread::scope(|s| s.spawn(|| job.clone()).join().unwrap())
+}
+
+#[test]
+fn explicit_predicate() {
+    let job = Job::<Holder, _> {
+        tag: "tag".to_owned(),
+        payload: vec![1u8],
+    };
+    let c = send_clone(&job);
+    assert_eq!((c.tag, c.payload), (job.tag, job.payload));
+}
+
+#[derive(Clone)]
+struct Callback<T: Pointee> {
+    map: Option<fn(T::A) -> T::A>,
+    hooks: Vec<fn(&T::A)>,
+}
+
+#[test]
+fn function_pointers() {
+    let c = Callback::<NotClone> {
+        map: Some(|a| a),
+        hooks: vec![|_| {}],
+    };
+    let NotClone = (c.clone().map.unwrap())(NotClone);
+    assert_eq!(c.clone().hooks.len(), 1);
+}
+
+trait Keyed {
+    type A: Clone + Ord + std::hash::Hash;
+}
+
+impl Keyed for NotClone {
+    type A = String;
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A)]
+struct Sets<T: Keyed> {
+    ordered: std::collections::BTreeSet<T::A>,
+    hashed: std::collections::HashSet<T::A>,
+}
+
+#[test]
+fn sets() {
+    let s = Sets::<NotClone> {
+        ordered: ["b".to_owned(), "a".to_owned()].into(),
+        hashed: ["c".to_owned()].into(),
+    };
+    let mut c = s.clone();
+    assert_eq!(c.ordered, s.ordered);
+    assert_eq!(c.hashed, s.hashed);
+
+    c.clone_from(&Sets {
+        ordered: Default::default(),
+        hashed: Default::default(),
+    });
+    assert!(c.ordered.is_empty() && c.hashed.is_empty());
+}
+
+struct Counter(std::cell::Cell<u32>);
+
+impl Counter {
+    /// Clone the counter, counting the clones of the original
+    fn tracked_clone(&self) -> Counter {
+        self.0.set(self.0.get() + 1);
+        Counter(std::cell::Cell::new(0))
+    }
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A)]
+struct Tracked<T: Associate> {
+    value: T::A,
+    #[clone(via = "self.counter.tracked_clone()")]
+    counter: Counter,
+}
+
+#[derive(Clone)]
+struct TrackedTuple(
+    u8,
+    #[clone(via = "Counter(std::cell::Cell::new(self.0.into()))")] Counter,
+);
+
+#[test]
+fn clone_via() {
+    let mut t = Tracked::<Holder> {
+        value: 1,
+        counter: Counter(std::cell::Cell::new(0)),
+    };
+    let c = t.clone();
+    assert_eq!(c.value, 1);
+    assert_eq!(t.counter.0.get(), 1);
+
+    t.clone_from(&c);
+    assert_eq!(c.counter.0.get(), 1);
+
+    let c = TrackedTuple(7, Counter(std::cell::Cell::new(0))).clone();
+    assert_eq!(c.1 .0.get(), 7);
+}
+
+/// Shadowed to a type with inherent `clone` and `eq` methods, the derives must not pick them
+struct Handle {
+    cloned: bool,
+    inner: Target,
+}
+
+struct Target;
+
+impl Target {
+    fn clone(&self) -> Handle {
+        panic!("Target::clone called instead of Clone::clone")
+    }
+
+    fn eq(&self, _other: &Handle) -> bool {
+        panic!("Target::eq called instead of PartialEq::eq")
+    }
+}
+
+impl std::ops::Deref for Handle {
+    type Target = Target;
+
+    fn deref(&self) -> &Target {
+        &self.inner
+    }
+}
+
+impl std::clone::Clone for Handle {
+    fn clone(&self) -> Self {
+        Handle {
+            cloned: true,
+            inner: Target,
+        }
+    }
+}
+
+impl std::cmp::PartialEq for Handle {
+    fn eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+#[derive(Clone, derive_bounded::PartialEq)]
+#[bounded_to(T::C)]
+struct Shadowed<T: Associate> {
+    handle: Handle,
+    c: T::C,
+}
+
+#[test]
+fn deref_target_methods() {
+    let d = Shadowed::<Holder> {
+        handle: Handle {
+            cloned: false,
+            inner: Target,
+        },
+        c: 1,
+    };
+
+    let c = d.clone();
+    assert!(c.handle.cloned);
+    assert!(c == d);
+}
+
+#[derive(Clone, Copy, std::fmt::Debug, PartialEq)]
+#[bounded(clone_copy)]
+struct Point<'a, const N: usize> {
+    x: i32,
+    y: (f64, [u8; N]),
+    label: &'a str,
+}
+
+#[derive(Clone, Copy, std::fmt::Debug, PartialEq)]
+#[bounded(clone_copy)]
+enum Step {
+    Stay,
+    Move(i8, i8),
+}
+
+/// Not trivially `Copy`, cloned field by field
+#[derive(Clone, std::fmt::Debug, PartialEq)]
+#[bounded(clone_copy)]
+struct Labeled {
+    x: i32,
+    label: String,
+}
+
+#[test]
+fn clone_copy() {
+    let p = Point {
+        x: 1,
+        y: (2.0, [3; 2]),
+        label: "a",
+    };
+    assert_eq!(p.clone(), p);
+    assert_eq!(Step::Move(1, -1).clone(), Step::Move(1, -1));
+
+    let l = Labeled {
+        x: 1,
+        label: "a".to_owned(),
+    };
+    assert_eq!(l.clone(), l);
+}
+
+/// Has the same fields as the `Facade` dereferencing to it
+#[derive(Clone, std::fmt::Debug, PartialEq)]
+struct Backing {
+    value: u8,
+}
+
+#[derive(Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+struct Facade {
+    value: u8,
+    backing: Backing,
+}
+
+impl std::ops::Deref for Facade {
+    type Target = Backing;
+
+    fn deref(&self) -> &Backing {
+        &self.backing
+    }
+}
+
+#[derive(Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+struct TupleFacade(u8, Backing);
+
+impl std::ops::Deref for TupleFacade {
+    type Target = (u8, u8);
+
+    fn deref(&self) -> &(u8, u8) {
+        &(0, 0)
+    }
+}
+
+#[test]
+fn deref_same_fields() {
+    let f = Facade {
+        value: 1,
+        backing: Backing { value: 2 },
+    };
+    let c = f.clone();
+    assert_eq!(c.value, 1);
+    assert_eq!(c.backing.value, 2);
+    assert_eq!(
+        format!("{:?}", c),
+        "Facade { value: 1, backing: Backing { value: 2 } }"
+    );
+    assert!(
+        c != Facade {
+            value: 2,
+            backing: Backing { value: 2 },
+        }
+    );
+
+    let t = TupleFacade(1, Backing { value: 2 });
+    assert_eq!(t.clone().0, 1);
+    assert!(t.clone() == t);
+    assert_eq!(format!("{:?}", t), "TupleFacade(1, Backing { value: 2 })");
+    assert!(t != TupleFacade(0, Backing { value: 2 }));
+}
+
+trait Plugin {
+    fn name(&self) -> String;
+    fn box_clone(&self) -> Box<dyn Plugin>;
+}
+
+#[derive(Clone)]
+#[bounded_to(T::B)]
+#[bounded(boxed_clone)]
+struct Greeter<T: Associate> {
+    greeting: T::B,
+}
+
+impl<T: Associate + 'static> Plugin for Greeter<T>
+where
+    T::B: std::fmt::Display,
+{
+    fn name(&self) -> String {
+        format!("{} plugin", self.greeting)
+    }
+
+    fn box_clone(&self) -> Box<dyn Plugin> {
+        self.boxed_clone()
+    }
+}
+
+#[test]
+fn boxed_clone() {
+    let plugin: Box<dyn Plugin> = Box::new(Greeter::<Holder> {
+        greeting: "hello".to_owned(),
+    });
+    let cloned = plugin.box_clone();
+    assert_eq!(cloned.name(), "hello plugin");
+}
+
+#[derive(Clone)]
+#[bounded_to(T::B)]
+struct Pinned<T: Associate> {
+    value: std::pin::Pin<Box<T::B>>,
+    maybe: Option<std::pin::Pin<std::sync::Arc<T::B>>>,
+}
+
+#[test]
+fn pinned() {
+    let p = Pinned::<Holder> {
+        value: Box::pin("a".to_owned()),
+        maybe: Some(std::sync::Arc::pin("b".to_owned())),
+    };
+    let c = p.clone();
+    assert_eq!(*c.value, "a");
+    assert_eq!(c.maybe.as_deref().map(String::as_str), Some("b"));
+}
+
+#[derive(Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+#[bounded_to(types(T::A, T::B))]
+struct Listed<T: Associate> {
+    a: T::A,
+    b: T::B,
+}
+
+#[test]
+fn types_list() {
+    let l = Listed::<Holder> {
+        a: 1,
+        b: "b".to_owned(),
+    };
+    assert_eq!(l.clone(), l);
+}
+
+trait Other {
+    type C: Clone;
+}
+
+impl Other for Holder {
+    type C = u32;
+}
+
+#[derive(Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+#[bounded_to((T::A, T::B), (U::C))]
+struct Grouped<T: Associate, U: Other> {
+    a: T::A,
+    b: T::B,
+    c: U::C,
+}
+
+#[derive(Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+#[bounded_to(((T::A, T::B)))]
+struct Paired<T: Associate> {
+    pair: (T::A, T::B),
+}
+
+#[test]
+fn grouped_types() {
+    let g = Grouped::<Holder, Holder> {
+        a: 1,
+        b: "b".to_owned(),
+        c: 3,
+    };
+    assert_eq!(g.clone(), g);
+
+    let p = Paired::<Holder> {
+        pair: (1, "b".to_owned()),
+    };
+    assert_eq!(p.clone(), p);
+}
@@ -35,6 +35,7 @@ struct C<T: Associate> {
     c: T::C,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, PartialEq, Debug)]
 #[bounded_to(T::C)]
 struct C2<T, V, Blah: Default>
@@ -46,6 +47,7 @@ where
     b: Blah,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, PartialEq, Debug)]
 #[bounded_to(T::A, T::B)]
 enum E<T: Associate> {
@@ -54,6 +56,12 @@ enum E<T: Associate> {
     C { a: T::A, b: T::C },
 }
 
+#[derive(Clone, PartialEq, Debug)]
+#[bounded_to(T::A, T::B)]
+struct Tuple<T: Associate> {
+    t: (T::A, T::B, u8),
+}
+
 #[test]
 fn debug() {
     let c = C { c: 42 };
@@ -71,3 +79,274 @@ fn debug() {
     dbg!(&a.b);
     dbg!(&a.b.c);
 }
+
+#[test]
+fn debug_tuple_field() {
+    let t = Tuple::<Holder> {
+        t: (1, "two".to_owned(), 3),
+    };
+
+    assert_eq!(t, t.clone());
+    assert_eq!(format!("{:?}", t), r#"Tuple { t: (1, "two", 3) }"#);
+}
+
+#[derive(Debug)]
+#[debug(with_discriminant)]
+enum Color {
+    Red = 1,
+    Green,
+    Blue = 4,
+}
+
+#[derive(Debug)]
+#[debug(with_discriminant)]
+#[repr(u64)]
+enum Big {
+    Small,
+    Large = 5_000_000_000,
+    Larger,
+}
+
+#[derive(Debug)]
+#[debug(with_discriminant)]
+#[repr(i64)]
+enum Signed {
+    Low = -5_000_000_000,
+    High = 5_000_000_000,
+}
+
+#[test]
+fn debug_with_discriminant() {
+    assert_eq!(format!("{:?}", Color::Red), "Red (1)");
+    assert_eq!(format!("{:?}", Color::Green), "Green (2)");
+    assert_eq!(format!("{:?}", Color::Blue), "Blue (4)");
+
+    assert_eq!(format!("{:?}", Big::Small), "Small (0)");
+    assert_eq!(format!("{:?}", Big::Large), "Large (5000000000)");
+    assert_eq!(format!("{:?}", Big::Larger), "Larger (5000000001)");
+    assert_eq!(format!("{:?}", Signed::Low), "Low (-5000000000)");
+    assert_eq!(format!("{:?}", Signed::High), "High (5000000000)");
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Skipping<T> {
+    name: &'static str,
+    #[debug(skip_if = "Vec::is_empty")]
+    items: Vec<T>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum SkippingEnum {
+    Tuple(#[debug(skip_if = "Option::is_none")] Option<u8>, u8),
+}
+
+#[test]
+fn debug_skip_if() {
+    let empty = Skipping::<u8> {
+        name: "empty",
+        items: vec![],
+    };
+    let full = Skipping {
+        name: "full",
+        items: vec![1],
+    };
+
+    assert_eq!(format!("{:?}", empty), r#"Skipping { name: "empty" }"#);
+    assert_eq!(
+        format!("{:?}", full),
+        r#"Skipping { name: "full", items: [1] }"#
+    );
+    assert_eq!(
+        format!("{:?}", SkippingEnum::Tuple(None, 2)),
+        "Tuple(.., 2)"
+    );
+    assert_eq!(
+        format!("{:?}", SkippingEnum::Tuple(Some(1), 2)),
+        "Tuple(Some(1), 2)"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+struct Triple(u8, #[debug(skip_if = "Option::is_none")] Option<u8>, u8);
+
+#[test]
+fn debug_skip_tuple_placeholder() {
+    assert_eq!(format!("{:?}", Triple(1, None, 3)), "Triple(1, .., 3)");
+    assert_eq!(
+        format!("{:?}", Triple(1, Some(2), 3)),
+        "Triple(1, Some(2), 3)"
+    );
+    assert_eq!(
+        format!("{:#?}", Triple(1, None, 3)),
+        "Triple(\n    1,\n    ..,\n    3,\n)"
+    );
+}
+
+// Type parameters must be used, so only const generics fit without fields
+#[derive(Debug)]
+struct Named<const N: usize> {}
+
+#[derive(Debug)]
+struct Unnamed<const N: usize>();
+
+#[derive(Debug)]
+struct Tag<T>(std::marker::PhantomData<T>);
+
+#[derive(Debug)]
+struct Unit;
+
+/// Not `Debug`, to check that no bound is required
+struct Opaque;
+
+#[test]
+fn debug_empty() {
+    assert_eq!(format!("{:?}", Named::<1> {}), "Named");
+    assert_eq!(format!("{:?}", Unnamed::<1>()), "Unnamed");
+    assert_eq!(
+        format!("{:?}", Tag::<Opaque>(std::marker::PhantomData)),
+        "Tag(PhantomData<debug::Opaque>)"
+    );
+    assert_eq!(format!("{:?}", Unit), "Unit");
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+#[bounded_to(T::A)]
+struct Log<T: Associate> {
+    #[debug(max_len = 3)]
+    entries: Vec<T::A>,
+    #[debug(max_len = 2, skip_if = "Vec::is_empty")]
+    tags: Vec<&'static str>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+enum Batch {
+    Items(#[debug(max_len = 1)] Vec<u8>),
+}
+
+#[test]
+fn debug_max_len() {
+    let log = |entries| Log::<Holder> {
+        entries,
+        tags: vec!["a", "b"],
+    };
+
+    assert_eq!(
+        format!("{:?}", log(vec![1, 2, 3, 4, 5])),
+        r#"Log { entries: [1, 2, 3, ..], tags: ["a", "b"] }"#
+    );
+    assert_eq!(
+        format!("{:?}", log(vec![1, 2, 3])),
+        r#"Log { entries: [1, 2, 3], tags: ["a", "b"] }"#
+    );
+    assert_eq!(
+        format!(
+            "{:?}",
+            Log::<Holder> {
+                entries: vec![],
+                tags: vec![]
+            }
+        ),
+        "Log { entries: [] }"
+    );
+    assert_eq!(format!("{:?}", Batch::Items(vec![1, 2])), "Items([1, ..])");
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+#[bounded(debug_pretty)]
+struct Pretty {
+    #[debug(max_len = 1)]
+    items: Vec<(u8, u8)>,
+    #[debug(skip_if = "Option::is_none")]
+    extra: Option<u8>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+#[bounded(debug_pretty)]
+enum PrettyEnum {
+    Pair(u8, u8),
+}
+
+#[test]
+fn debug_alternate() {
+    let pretty = "Pretty {\n    items: [\n        (\n            1,\n            2,\n        ),\n        ..,\n    ],\n}";
+    let p = Pretty {
+        items: vec![(1, 2), (3, 4)],
+        extra: None,
+    };
+    assert_eq!(format!("{:#?}", p), pretty);
+    assert_eq!(format!("{:?}", p), pretty);
+    assert_eq!(
+        format!("{:?}", PrettyEnum::Pair(1, 2)),
+        "Pair(\n    1,\n    2,\n)"
+    );
+
+    // Without the option the flag is still forwarded to the custom formatted fields
+    let log = Log::<Holder> {
+        entries: vec![1, 2],
+        tags: vec!["a", "b", "c"],
+    };
+    assert_eq!(
+        format!("{:#?}", log),
+        "Log {\n    entries: [\n        1,\n        2,\n    ],\n    tags: [\n        \"a\",\n        \"b\",\n        ..,\n    ],\n}"
+    );
+}
+
+#[allow(dead_code)]
+#[derive(Debug)]
+#[bounded(debug_tuple_as_struct)]
+#[bounded_to(T::A)]
+struct Indexed<T: Associate>(
+    T::A,
+    #[debug(skip_if = "Option::is_none")] Option<u8>,
+    &'static str,
+);
+
+#[test]
+fn debug_tuple_as_struct() {
+    assert_eq!(
+        format!("{:?}", Indexed::<Holder>(1, None, "a")),
+        r#"Indexed { 0: 1, 2: "a" }"#
+    );
+    assert_eq!(
+        format!("{:?}", Indexed::<Holder>(1, Some(2), "a")),
+        r#"Indexed { 0: 1, 1: Some(2), 2: "a" }"#
+    );
+}
+
+impl Associate for Opaque {
+    type A = usize;
+    type B = String;
+    type C = u32;
+}
+
+#[allow(dead_code)]
+#[derive(Clone, Debug)]
+#[bounded_to(T::A)]
+#[debug(bound = "T::A: std::fmt::Debug, T: std::fmt::Debug")]
+struct Shared<T: Associate> {
+    a: T::A,
+    owner: std::rc::Rc<T>,
+}
+
+#[test]
+fn debug_bound() {
+    // Clone does not need `T: Debug`
+    let s = Shared {
+        a: 1,
+        owner: std::rc::Rc::new(Opaque),
+    };
+    assert_eq!(s.clone().a, 1);
+
+    let s = Shared {
+        a: 1,
+        owner: std::rc::Rc::new(Holder),
+    };
+    assert_eq!(format!("{:?}", s), "Shared { a: 1, owner: Holder }");
+}
@@ -47,9 +47,211 @@ where
     b: Blah,
 }
 
+struct NotDefault;
+
+#[derive(Default)]
+#[bounded_to(T::A)]
+enum Setting<T: Associate, U> {
+    Off(U),
+    #[default]
+    Config {
+        size: T::A,
+    },
+}
+
+/// Marker declaring its traits without bounds on `T`
+struct Tag<T>(std::marker::PhantomData<T>);
+
+impl<T> std::clone::Clone for Tag<T> {
+    fn clone(&self) -> Self {
+        Tag(std::marker::PhantomData)
+    }
+}
+
+impl<T> std::default::Default for Tag<T> {
+    fn default() -> Self {
+        Tag(std::marker::PhantomData)
+    }
+}
+
+#[derive(Default, derive_bounded::Clone)]
+#[bounded(markers(Tag))]
+struct Marked<T, U> {
+    data: std::marker::PhantomData<T>,
+    pin: std::marker::PhantomPinned,
+    tag: Tag<U>,
+    count: u32,
+}
+
+#[derive(Default)]
+#[bounded_to(T::A)]
+struct List<'a, T: Associate> {
+    name: &'a str,
+    value: T::A,
+    next: Option<Box<Self>>,
+}
+
+#[derive(Default)]
+struct Pair<'a>(&'a [u8], Vec<&'a str>);
+
+#[derive(Default, Debug, PartialEq)]
+enum Level {
+    Low,
+    #[default]
+    Mid,
+    High,
+}
+
 #[test]
 fn default() {
     let c2 = C2::<Holder, usize, usize>::default();
 
     dbg!(&c2);
 }
+
+#[test]
+fn default_enum() {
+    let s = Setting::<Holder, NotDefault>::default();
+
+    assert!(matches!(s, Setting::Config { size: 0 }));
+    assert_eq!(Level::default(), Level::Mid);
+}
+
+#[test]
+fn default_markers() {
+    let m = Marked::<NotDefault, NotDefault>::default();
+    let c = m.clone();
+
+    assert_eq!(c.count, 0);
+}
+
+#[test]
+fn default_field_types() {
+    let l = List::<Holder>::default();
+    let p = Pair::default();
+
+    assert_eq!(l.name, "");
+    assert_eq!(l.value, 0);
+    assert!(l.next.is_none());
+    assert!(p.0.is_empty() && p.1.is_empty());
+}
+
+trait Unbounded {
+    type A;
+}
+
+struct NoDefault(u8);
+
+impl Unbounded for Holder {
+    type A = NoDefault;
+}
+
+fn no_default() -> NoDefault {
+    NoDefault(7)
+}
+
+#[derive(Default)]
+struct Values<T: Unbounded> {
+    #[default = 42]
+    answer: u32,
+    #[default(value = "String::from(\"name\")")]
+    name: String,
+    #[default(with = "no_default")]
+    custom: NoDefault,
+    #[default(value = "no_default()")]
+    assoc: NoDefault,
+    plain: Vec<u8>,
+    marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Default)]
+enum Choice {
+    #[default]
+    First(#[default = 3] u8, #[default(with = "no_default")] NoDefault),
+    Second,
+}
+
+#[test]
+fn field_values() {
+    let v = Values::<Holder>::default();
+
+    assert_eq!(v.answer, 42);
+    assert_eq!(v.name, "name");
+    assert_eq!(v.custom.0, 7);
+    assert_eq!(v.assoc.0, 7);
+    assert!(v.plain.is_empty());
+
+    let Choice::First(n, c) = Choice::default() else {
+        unreachable!()
+    };
+    assert_eq!((n, c.0), (3, 7));
+}
+
+#[derive(Default)]
+#[bounded_to(T::A)]
+#[non_exhaustive]
+struct Settings<T: Associate> {
+    #[default = 8]
+    retries: u8,
+    value: T::A,
+}
+
+#[derive(Default)]
+#[non_exhaustive]
+struct Flags(bool, #[default = true] bool);
+
+#[test]
+fn non_exhaustive() {
+    let s = Settings::<Holder>::default();
+    assert_eq!(s.retries, 8);
+
+    let f = Flags::default();
+    assert_eq!((f.0, f.1), (false, true));
+}
+
+/// The associated types are not required to implement `Default`, the impls bound them
+mod chain {
+    use derive_bounded::Default;
+
+    pub trait Loose {
+        type A;
+        type B;
+        type C;
+    }
+
+    pub struct Holder;
+
+    impl Loose for Holder {
+        type A = u8;
+        type B = String;
+        type C = Vec<u16>;
+    }
+
+    #[derive(Default)]
+    #[bounded_to(T::A, T::B, T::C)]
+    pub struct A<T: Loose> {
+        pub a: T::A,
+        pub b: B<T>,
+    }
+
+    #[derive(Default)]
+    #[bounded_to(T::B, T::C)]
+    pub struct B<T: Loose> {
+        pub b: T::B,
+        pub c: C<T>,
+    }
+
+    #[derive(Default)]
+    #[bounded_to(T::C)]
+    pub struct C<T: Loose> {
+        pub c: T::C,
+    }
+}
+
+#[test]
+fn default_chain() {
+    let a = chain::A::<chain::Holder>::default();
+    assert_eq!(a.a, 0);
+    assert_eq!(a.b.b, "");
+    assert!(a.b.c.c.is_empty());
+}
@@ -0,0 +1,40 @@
+use derive_bounded::Display;
+
+trait Associate {
+    type A: std::fmt::Display;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = f32;
+}
+
+#[derive(Display)]
+#[display(join = ", ")]
+struct Record<T> {
+    id: T,
+    name: String,
+    score: u8,
+}
+
+#[derive(Display)]
+#[display(join = " | ")]
+#[bounded_to(T::A)]
+struct Row<T: Associate>(T::A, &'static str);
+
+#[derive(Display)]
+#[display(join = "-")]
+struct Nothing {}
+
+#[test]
+fn display_join() {
+    let r = Record {
+        id: 7,
+        name: "a".to_owned(),
+        score: 3,
+    };
+    assert_eq!(r.to_string(), "7, a, 3");
+    assert_eq!(Row::<Holder>(1.5, "b").to_string(), "1.5 | b");
+    assert_eq!(Nothing {}.to_string(), "");
+}
@@ -0,0 +1,70 @@
+use std::collections::HashSet;
+
+use derive_bounded::{Debug, HashEq, PartialEq, PartialOrd};
+
+trait Associate {
+    type A: std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u32;
+}
+
+#[derive(Debug, HashEq)]
+#[bounded_to(T::A)]
+#[repr(C)]
+struct Packet<T: Associate> {
+    header: u32,
+    body: [T::A],
+}
+
+impl Packet<Holder> {
+    /// View `words` as a packet, the first one being the header
+    fn from_words(words: &[u32]) -> &Self {
+        assert!(!words.is_empty());
+        let body = std::ptr::slice_from_raw_parts(words.as_ptr(), words.len() - 1);
+        // SAFETY: the struct is `repr(C)` and made of `u32`s only, the slice metadata is the
+        // length of the body
+        unsafe { &*(body as *const Self) }
+    }
+}
+
+#[derive(Debug, PartialEq, PartialOrd)]
+struct Frame<B: ?Sized> {
+    len: usize,
+    body: B,
+}
+
+/// Never built, the impls only need to compile
+#[allow(dead_code)]
+#[derive(Debug, HashEq)]
+struct Text {
+    len: usize,
+    body: str,
+}
+
+#[test]
+fn unsized_last_field() {
+    let words = [7, 1, 2];
+    let p = Packet::<Holder>::from_words(&words);
+    assert_eq!(format!("{:?}", p), "Packet { header: 7, body: [1, 2] }");
+    assert_eq!(p, Packet::from_words(&[7, 1, 2]));
+    assert_ne!(p, Packet::from_words(&[7, 1]));
+
+    let set: HashSet<&Packet<Holder>> = [p, Packet::from_words(&words)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let f: &Frame<[u8]> = &Frame {
+        len: 2,
+        body: [1, 2],
+    };
+    let g: &Frame<[u8]> = &Frame {
+        len: 2,
+        body: [1, 3],
+    };
+    assert_eq!(format!("{:?}", f), "Frame { len: 2, body: [1, 2] }");
+    assert!(f != g);
+    assert!(f < g);
+}
@@ -0,0 +1,24 @@
+use std::collections::HashSet;
+
+use derive_bounded::{Clone, Debug, Default, HashEq, Ord, PartialOrd};
+
+#[derive(Clone, Debug, Default, HashEq, PartialOrd, Ord)]
+struct Unit();
+
+#[test]
+fn empty_tuple_struct() {
+    let u = Unit::default();
+
+    assert_eq!(u.clone(), Unit());
+    assert_eq!(format!("{:?}", u), "Unit");
+    assert_eq!(format!("{:#?}", u), "Unit");
+    assert_eq!(u.cmp(&Unit()), std::cmp::Ordering::Equal);
+    assert_eq!(u.partial_cmp(&Unit()), Some(std::cmp::Ordering::Equal));
+
+    let set: HashSet<_> = [Unit(), Unit()].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let mut v = Unit();
+    v.clone_from(&u);
+    assert_eq!(v, u);
+}
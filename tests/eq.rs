@@ -0,0 +1,88 @@
+#![allow(dead_code)]
+use derive_bounded::{Eq, PartialEq};
+
+trait Associate {
+    type A: std::cmp::PartialEq;
+    type B: std::cmp::Eq;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = f64;
+    type B = u32;
+}
+
+/// Equality only needs `T::A`, while totality is guaranteed by `T::B`
+struct Wrapper<T: Associate> {
+    a: T::A,
+    b: T::B,
+}
+
+impl<T: Associate> std::cmp::PartialEq for Wrapper<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.b == other.b
+    }
+}
+
+impl<T: Associate> std::cmp::Eq for Wrapper<T> where T::B: std::cmp::Eq {}
+
+#[derive(PartialEq, Eq)]
+#[bounded_to(T::A)]
+#[bounded(eq_bound(T::B))]
+struct Pair<T: Associate> {
+    w: Wrapper<T>,
+}
+
+#[derive(PartialEq, Eq)]
+#[bounded_to(T::A)]
+#[bounded(eq_bound = "<T as Associate>::B")]
+enum Either<T: Associate> {
+    W(Wrapper<T>),
+    N,
+}
+
+fn assert_eq<E: std::cmp::Eq>(_: &E) {}
+
+#[test]
+fn eq_bound() {
+    let a = Pair::<Holder> {
+        w: Wrapper { a: 1.0, b: 2 },
+    };
+    let e = Either::<Holder>::W(Wrapper { a: 1.0, b: 2 });
+
+    assert_eq(&a);
+    assert_eq(&e);
+
+    assert!(a == a);
+    assert!(e != Either::N);
+}
+
+#[derive(PartialEq, Eq)]
+struct Level3<T> {
+    t: T,
+}
+
+#[derive(PartialEq, Eq)]
+struct Level2<T> {
+    c: Level3<T>,
+    n: u8,
+}
+
+#[derive(PartialEq, Eq)]
+enum Level1<T> {
+    B(Level2<T>),
+    Empty,
+}
+
+#[test]
+fn eq_chain() {
+    let a = Level1::B(Level2 {
+        c: Level3 { t: "t" },
+        n: 1,
+    });
+
+    assert_eq(&a);
+    assert!(a == a);
+    assert!(a != Level1::Empty);
+}
@@ -0,0 +1,39 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug};
+
+trait Graph {
+    type Node;
+    type Weight: std::clone::Clone + std::fmt::Debug;
+
+    fn weight(node: &Self::Node) -> Self::Weight;
+}
+
+#[derive(Clone, Debug)]
+#[bounded_to(T::Weight)]
+struct Node<T: Graph<Node = Self>> {
+    weight: T::Weight,
+    edges: Vec<usize>,
+}
+
+struct Weighted;
+
+impl Graph for Weighted {
+    type Node = Node<Weighted>;
+    type Weight = u32;
+
+    fn weight(node: &Node<Weighted>) -> u32 {
+        node.weight
+    }
+}
+
+#[test]
+fn self_referential_bound() {
+    let node = Node::<Weighted> {
+        weight: 3,
+        edges: vec![1, 2],
+    };
+
+    let cloned = node.clone();
+    assert_eq!(Weighted::weight(&cloned), 3);
+    assert_eq!(format!("{:?}", cloned), "Node { weight: 3, edges: [1, 2] }");
+}
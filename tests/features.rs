@@ -0,0 +1,18 @@
+//! The derives behind a feature, run with `cargo test --features wrapper` and without it.
+
+#[cfg(feature = "wrapper")]
+#[test]
+fn wrapper_enabled() {
+    #[derive(derive_bounded::Wrapper)]
+    struct Meters(f64);
+
+    assert_eq!(Meters(1.5).into_inner(), 1.5);
+    assert_eq!(*Meters(2.5).as_inner(), 2.5);
+}
+
+#[cfg(not(feature = "wrapper"))]
+#[test]
+fn wrapper_disabled() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/features/wrapper_disabled.rs");
+}
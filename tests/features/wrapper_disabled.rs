@@ -0,0 +1,4 @@
+#[derive(derive_bounded::Wrapper)]
+struct Meters(f64);
+
+fn main() {}
@@ -0,0 +1,113 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, Default, PartialEq};
+
+trait Associate {
+    type A: PartialEq + Clone + Default + std::fmt::Debug;
+}
+
+#[derive(std::fmt::Debug)]
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+#[rustfmt::skip]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[bounded_to(T::A,)]
+struct TrailingComma<T: Associate,> {
+    a: T::A,
+}
+
+#[rustfmt::skip]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[bounded_to(T::A)]
+struct EmptyWhere<T: Associate>
+where
+{
+    a: T::A,
+}
+
+#[rustfmt::skip]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[bounded_to(T::A)]
+struct TupleEmptyWhere<T: Associate>(T::A)
+where;
+
+#[rustfmt::skip]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[bounded_to(
+    T::A,
+    U::A,
+)]
+struct MultilineWhere<T, U,>
+where
+    T: Associate,
+    U: Associate<A = u8>,
+{
+    t: T::A,
+    u: U::A,
+}
+
+trait Nested {
+    type N: Associate;
+}
+
+struct Nest;
+
+impl Nested for Nest {
+    type N = Holder;
+}
+
+#[rustfmt::skip]
+#[derive(Clone, Debug, Default, PartialEq)]
+#[bounded_to(
+    // the plain associated type
+    T::A,
+
+    /* the nested ones */
+    <T::N as Associate>::A,
+    <<T as Nested>::N as Associate>::A,
+)]
+struct MultilineBoundedTo<T: Nested + Associate> {
+    a: T::A,
+    n: <T::N as Associate>::A,
+    q: <<T as Nested>::N as Associate>::A,
+}
+
+impl Associate for Nest {
+    type A = u16;
+}
+
+/// Documentation between the derive and the item
+#[derive(Clone, Debug, Default, PartialEq)]
+#[allow(non_camel_case_types)]
+#[bounded_to(T::A)]
+#[repr(C)]
+struct lower_case<T: Associate> {
+    a: T::A,
+}
+
+#[test]
+fn odd_formatting() {
+    let t = TrailingComma::<Holder>::default();
+    assert_eq!(t, t.clone());
+
+    let e = EmptyWhere::<Holder>::default();
+    assert_eq!(e, e.clone());
+
+    let e = TupleEmptyWhere::<Holder>::default();
+    assert_eq!(format!("{:?}", e), "TupleEmptyWhere(0)");
+
+    let m = MultilineWhere::<Holder, Holder>::default();
+    assert_eq!(format!("{:?}", m.clone()), "MultilineWhere { t: 0, u: 0 }");
+
+    let b = MultilineBoundedTo::<Nest>::default();
+    assert_eq!(
+        format!("{:?}", b.clone()),
+        "MultilineBoundedTo { a: 0, n: 0, q: 0 }"
+    );
+
+    let l = lower_case::<Holder>::default();
+    assert_eq!(l, l.clone());
+}
@@ -0,0 +1,20 @@
+use derive_bounded::{Debug, Default, FromStr, PartialEq};
+
+#[derive(Debug, Default, FromStr, PartialEq)]
+enum Color {
+    Red,
+    #[default]
+    Green,
+    Blue = 7,
+}
+
+#[test]
+fn parse_and_default() {
+    assert_eq!(Color::default(), Color::Green);
+    assert_eq!("Red".parse(), Ok(Color::Red));
+    assert_eq!("Blue".parse::<Color>(), Ok(Color::Blue));
+    assert_eq!(
+        "red".parse::<Color>(),
+        Err("unknown variant `red` of `Color`".to_owned())
+    );
+}
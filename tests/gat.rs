@@ -0,0 +1,68 @@
+use std::collections::HashSet;
+
+use derive_bounded::{Clone, Debug, HashEq};
+
+trait Family {
+    type Borrowed<'a>: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug
+    where
+        Self: 'a;
+    type Wrapped<X>: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug
+    where
+        X: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+}
+
+struct Strings;
+
+impl Family for Strings {
+    type Borrowed<'a> = &'a str;
+    type Wrapped<X>
+        = Vec<X>
+    where
+        X: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[bounded_to(T::Borrowed<'a>, T::Wrapped<u8>)]
+struct Gat<'a, T: Family + 'a> {
+    borrowed: T::Borrowed<'a>,
+    wrapped: T::Wrapped<u8>,
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[bounded_to(T::Wrapped<U>)]
+struct GatParam<T: Family, U: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug>(
+    T::Wrapped<U>,
+);
+
+/// Implements none of the derived traits
+struct Opaque;
+
+impl Family for Opaque {
+    type Borrowed<'a> = u8;
+    type Wrapped<X>
+        = Option<X>
+    where
+        X: std::clone::Clone + std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+}
+
+#[test]
+fn generic_associated_types() {
+    let owned = String::from("a");
+    let g = Gat::<Strings> {
+        borrowed: &owned,
+        wrapped: vec![1, 2],
+    };
+
+    assert_eq!(g.clone(), g);
+    assert_eq!(
+        format!("{:?}", g),
+        r#"Gat { borrowed: "a", wrapped: [1, 2] }"#
+    );
+
+    let set: HashSet<_> = [g.clone(), g].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let p = GatParam::<Opaque, u16>(Some(1));
+    assert_eq!(p.clone(), p);
+    assert_eq!(format!("{:?}", p), "GatParam(Some(1))");
+}
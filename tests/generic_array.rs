@@ -0,0 +1,69 @@
+//! Mimics `generic-array`, where the length is a type parameter implementing `ArrayLength`
+use derive_bounded::{Clone, Debug, PartialEq};
+
+trait ArrayLength<T> {
+    type Storage: std::clone::Clone + std::fmt::Debug + std::cmp::PartialEq;
+}
+
+/// Like the `typenum` lengths, but implementing none of the derived traits
+struct U2;
+
+impl<T> ArrayLength<T> for U2
+where
+    T: std::clone::Clone + std::fmt::Debug + std::cmp::PartialEq,
+{
+    type Storage = [T; 2];
+}
+
+struct GenericArray<T, N: ArrayLength<T>> {
+    data: N::Storage,
+}
+
+impl<T, N: ArrayLength<T>> std::clone::Clone for GenericArray<T, N> {
+    fn clone(&self) -> Self {
+        GenericArray {
+            data: self.data.clone(),
+        }
+    }
+}
+
+impl<T, N: ArrayLength<T>> std::fmt::Debug for GenericArray<T, N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.data.fmt(f)
+    }
+}
+
+impl<T, N: ArrayLength<T>> std::cmp::PartialEq for GenericArray<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+
+trait Associate {
+    type A: std::clone::Clone + std::fmt::Debug + std::cmp::PartialEq;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[bounded_to(T::A)]
+#[bounded(markers(GenericArray))]
+struct Block<T: Associate, N: ArrayLength<T::A>> {
+    data: GenericArray<T::A, N>,
+    len: usize,
+}
+
+#[test]
+fn type_level_length() {
+    let b = Block::<Holder, U2> {
+        data: GenericArray { data: [1, 2] },
+        len: 2,
+    };
+
+    assert!(b.clone() == b);
+    assert_eq!(format!("{:?}", b), "Block { data: [1, 2], len: 2 }");
+}
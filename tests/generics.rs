@@ -0,0 +1,180 @@
+use std::collections::HashSet;
+use std::marker::PhantomData;
+
+use derive_bounded::{Clone, Debug, Default, HashEq};
+
+trait Associate {
+    type A: std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+    type B: Clone + Default + std::cmp::Eq + std::hash::Hash + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = String;
+    type B = u8;
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[bounded_to(T::A, T::B)]
+struct All<'a, T: Associate, const N: usize> {
+    r: &'a T::A,
+    arr: [T::B; N],
+}
+
+#[derive(Clone, Debug, Default, HashEq)]
+#[bounded_to(T::A, T::B)]
+struct AllDefault<'a, T: Associate, const N: usize> {
+    r: Option<&'a T::A>,
+    b: T::B,
+    len: PhantomData<[(); N]>,
+}
+
+#[test]
+fn lifetimes_consts_and_types() {
+    let s = "a".to_owned();
+    let all = All::<Holder, 3> {
+        r: &s,
+        arr: [1, 2, 3],
+    };
+
+    assert_eq!(all.clone(), all);
+    assert_eq!(format!("{:?}", all), r#"All { r: "a", arr: [1, 2, 3] }"#);
+
+    let set: HashSet<_> = [all.clone(), all].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let d = AllDefault::<Holder, 4>::default();
+    assert_eq!(d.clone(), d);
+    assert_eq!(
+        format!("{:?}", d),
+        "AllDefault { r: None, b: 0, len: PhantomData<[(); 4]> }"
+    );
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[bounded_to(T::B)]
+struct Cell<T: Associate> {
+    b: T::B,
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[bounded_to(T::B)]
+struct Grid<T: Associate, const N: usize> {
+    cells: [[Cell<T>; N]; N],
+}
+
+#[test]
+fn nested_arrays() {
+    let cell = |b| Cell::<Holder> { b };
+    let grid = Grid::<Holder, 2> {
+        cells: [[cell(1), cell(2)], [cell(3), cell(4)]],
+    };
+
+    assert_eq!(grid.clone(), grid);
+    assert_eq!(
+        format!("{:?}", grid),
+        "Grid { cells: [[Cell { b: 1 }, Cell { b: 2 }], [Cell { b: 3 }, Cell { b: 4 }]] }"
+    );
+}
+
+#[derive(Clone, Debug, Default, derive_bounded::PartialEq, derive_bounded::Eq)]
+struct Borrowed<'a> {
+    s: &'a str,
+}
+
+#[derive(Clone, Debug, Default, derive_bounded::PartialEq, derive_bounded::Eq)]
+struct BorrowedTuple<'a, 'b: 'a>(&'a [u8], Option<&'b str>);
+
+#[derive(Clone, Debug, Default, derive_bounded::PartialEq, derive_bounded::Eq)]
+enum BorrowedEnum<'a> {
+    #[default]
+    None,
+    Some(&'a str),
+}
+
+fn assert_eq<E: std::cmp::Eq>(_: &E) {}
+
+#[test]
+fn lifetimes_only() {
+    let owned = String::from("a");
+    let b = Borrowed { s: &owned };
+    assert_eq(&b);
+    assert!(b.clone() == b);
+    assert!(Borrowed::default() != b);
+    assert_eq!(format!("{:?}", b), r#"Borrowed { s: "a" }"#);
+
+    let t = BorrowedTuple(&[1], Some(&owned));
+    assert_eq(&t);
+    assert!(t.clone() == t);
+    assert!(BorrowedTuple::default() != t);
+    assert_eq!(format!("{:?}", t), r#"BorrowedTuple([1], Some("a"))"#);
+
+    let e = BorrowedEnum::Some(&owned);
+    assert_eq(&e);
+    assert!(e.clone() == e);
+    assert!(BorrowedEnum::default() != e);
+    assert_eq!(format!("{:?}", e), r#"Some("a")"#);
+}
+
+#[derive(Clone, Debug, Default, derive_bounded::PartialEq, derive_bounded::Eq)]
+struct Marker<T>(PhantomData<T>);
+
+/// Implements none of the derived traits
+struct Opaque;
+
+#[test]
+fn tuple_phantom_data() {
+    let m = Marker::<Opaque>::default();
+    assert_eq(&m);
+    assert!(m.clone() == m);
+    assert_eq!(format!("{:?}", m), "Marker(PhantomData<generics::Opaque>)");
+}
+
+#[derive(Clone, Debug, derive_bounded::PartialEq)]
+#[bounded_to(T::B)]
+struct Nested<T: Associate> {
+    flat: Vec<T::B>,
+    deep: Vec<Vec<T::B>>,
+    #[allow(clippy::box_collection)]
+    boxed: Box<Vec<T::B>>,
+    slice: Option<Box<[T::B]>>,
+}
+
+#[test]
+fn nested_containers() {
+    let n = Nested::<Holder> {
+        flat: vec![1],
+        deep: vec![vec![2], vec![]],
+        boxed: Box::new(vec![3]),
+        slice: Some(vec![4, 5].into_boxed_slice()),
+    };
+
+    assert!(n.clone() == n);
+    assert_eq!(
+        format!("{:?}", n),
+        "Nested { flat: [1], deep: [[2], []], boxed: [3], slice: Some([4, 5]) }"
+    );
+}
+
+#[derive(Clone, Debug, derive_bounded::PartialEq)]
+#[bounded(where_self = "std::default::Default")]
+struct Resettable<T> {
+    value: T,
+}
+
+impl<T: std::default::Default> std::default::Default for Resettable<T> {
+    fn default() -> Self {
+        Resettable {
+            value: T::default(),
+        }
+    }
+}
+
+#[test]
+fn where_self() {
+    let r = Resettable { value: 3u8 };
+    assert!(r.clone() == r);
+    assert!(Resettable::default() != r);
+    assert_eq!(format!("{:?}", r), "Resettable { value: 3 }");
+}
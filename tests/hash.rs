@@ -0,0 +1,80 @@
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use derive_bounded::{Eq, PartialEq};
+
+trait Associate {
+    type A: std::cmp::Eq + Hash;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = String;
+}
+
+#[derive(PartialEq, Eq, derive_bounded::Hash)]
+#[bounded_to(T::A)]
+struct Key<T: Associate> {
+    a: T::A,
+    n: u8,
+}
+
+#[derive(PartialEq, Eq, derive_bounded::Hash)]
+#[bounded_to(T::A)]
+struct Id<T: Associate>(T::A);
+
+#[derive(PartialEq, Eq, derive_bounded::Hash)]
+#[bounded_to(T::A)]
+enum Entry<T: Associate> {
+    Empty,
+    Other,
+    Short(T::A),
+    Long { a: T::A, n: u8 },
+}
+
+#[derive(PartialEq, Eq, derive_bounded::Hash)]
+struct Unit;
+
+#[test]
+fn hash() {
+    let key = |a: &str, n| Key::<Holder> { a: a.to_owned(), n };
+    let keys: HashSet<_> = [key("a", 1), key("a", 1), key("a", 2), key("b", 1)]
+        .into_iter()
+        .collect();
+    assert_eq!(keys.len(), 3);
+
+    let ids: HashSet<_> = [Id::<Holder>("a".to_owned()), Id("a".to_owned())]
+        .into_iter()
+        .collect();
+    assert_eq!(ids.len(), 1);
+
+    let entries: HashSet<_> = [
+        Entry::<Holder>::Empty,
+        Entry::Empty,
+        Entry::Other,
+        Entry::Short("a".to_owned()),
+        Entry::Short("a".to_owned()),
+        Entry::Long {
+            a: "a".to_owned(),
+            n: 1,
+        },
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(entries.len(), 4);
+
+    let units: HashSet<_> = [Unit, Unit].into_iter().collect();
+    assert_eq!(units.len(), 1);
+}
+
+#[test]
+fn equal_values_hash_the_same() {
+    let state = std::collections::hash_map::RandomState::new();
+    let a = Entry::<Holder>::Short("a".to_owned());
+    let b = Entry::<Holder>::Short("a".to_owned());
+
+    assert!(a == b);
+    assert_eq!(state.hash_one(&a), state.hash_one(&b));
+    assert_eq!(state.hash_one(Unit), state.hash_one(Unit));
+}
@@ -0,0 +1,281 @@
+#![allow(dead_code)]
+use std::collections::HashSet;
+use std::hash::{BuildHasher, Hash};
+
+use derive_bounded::HashEq;
+
+trait Associate {
+    type A: std::cmp::Eq + Hash;
+    type B: std::cmp::Eq + Hash;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u32;
+    type B = String;
+}
+
+#[derive(HashEq)]
+#[bounded_to(T::A, T::B)]
+struct Key<T: Associate> {
+    a: T::A,
+    b: T::B,
+}
+
+#[derive(HashEq)]
+#[bounded_to(T::A)]
+struct Id<T: Associate>(T::A, u8);
+
+#[derive(HashEq)]
+#[bounded_to(T::A, T::B)]
+enum Entry<T: Associate> {
+    Empty,
+    Short(T::A),
+    Long { a: T::A, b: T::B },
+}
+
+#[derive(HashEq)]
+struct Nothing {}
+
+#[test]
+fn hash_eq() {
+    let mut set = HashSet::new();
+
+    set.insert(Key::<Holder> {
+        a: 1,
+        b: "one".to_owned(),
+    });
+    set.insert(Key::<Holder> {
+        a: 1,
+        b: "one".to_owned(),
+    });
+    set.insert(Key::<Holder> {
+        a: 2,
+        b: "two".to_owned(),
+    });
+
+    assert_eq!(set.len(), 2);
+
+    let ids: HashSet<_> = [Id::<Holder>(1, 1), Id(1, 1), Id(1, 2)]
+        .into_iter()
+        .collect();
+    assert_eq!(ids.len(), 2);
+
+    let entries: HashSet<_> = [
+        Entry::<Holder>::Empty,
+        Entry::Empty,
+        Entry::Short(1),
+        Entry::Long {
+            a: 1,
+            b: "one".to_owned(),
+        },
+    ]
+    .into_iter()
+    .collect();
+    assert_eq!(entries.len(), 3);
+
+    assert!(Nothing {} == Nothing {});
+    let state = std::collections::hash_map::RandomState::new();
+    assert_eq!(state.hash_one(Nothing {}), state.hash_one(Nothing {}));
+}
+
+trait Table {
+    type Raw: Copy + std::cmp::Eq + Hash;
+}
+
+struct Users;
+
+impl Table for Users {
+    type Raw = std::num::NonZeroU32;
+}
+
+#[derive(HashEq)]
+#[bounded_to(T::Raw)]
+#[repr(transparent)]
+struct RowId<T: Table>(T::Raw);
+
+#[test]
+fn transparent_non_zero() {
+    let one = std::num::NonZeroU32::new(1).unwrap();
+    let two = std::num::NonZeroU32::new(2).unwrap();
+
+    let mut set = HashSet::new();
+    assert!(set.insert(RowId::<Users>(one)));
+    assert!(set.insert(RowId::<Users>(two)));
+    assert!(!set.insert(RowId::<Users>(one)));
+    assert_eq!(set.len(), 2);
+    assert_eq!(
+        std::mem::size_of::<Option<RowId<Users>>>(),
+        std::mem::size_of::<u32>()
+    );
+}
+
+#[derive(HashEq)]
+struct Tags {
+    #[partial_eq(unordered)]
+    tags: Vec<String>,
+    name: u8,
+}
+
+#[derive(HashEq)]
+enum Group {
+    Tags(
+        #[hash(unordered)]
+        #[partial_eq(unordered)]
+        Vec<u8>,
+    ),
+}
+
+#[test]
+fn unordered() {
+    let hasher = std::collections::hash_map::RandomState::new();
+    let a = Tags {
+        tags: vec!["a".into(), "b".into(), "a".into()],
+        name: 1,
+    };
+    let b = Tags {
+        tags: vec!["b".into(), "a".into(), "a".into()],
+        name: 1,
+    };
+    let c = Tags {
+        tags: vec!["b".into(), "b".into(), "a".into()],
+        name: 1,
+    };
+
+    assert!(a == b);
+    assert!(a != c);
+    assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+
+    let g = Group::Tags(vec![1, 2, 3]);
+    let h = Group::Tags(vec![3, 1, 2]);
+    assert!(g == h);
+    assert_eq!(hasher.hash_one(&g), hasher.hash_one(&h));
+}
+
+fn hash_bits(value: &f64, state: &mut dyn std::hash::Hasher) {
+    state.write_u64(value.to_bits());
+}
+
+#[derive(HashEq)]
+struct Measure {
+    name: String,
+    #[partial_eq(total_float)]
+    #[hash(with = "hash_bits")]
+    value: f64,
+}
+
+#[test]
+fn hash_with() {
+    let hasher = std::collections::hash_map::RandomState::new();
+    let measure = |value| Measure {
+        name: "m".to_owned(),
+        value,
+    };
+
+    assert!(measure(f64::NAN) == measure(f64::NAN));
+    assert_eq!(
+        hasher.hash_one(measure(f64::NAN)),
+        hasher.hash_one(measure(f64::NAN))
+    );
+    assert_ne!(hasher.hash_one(measure(1.0)), hasher.hash_one(measure(2.0)));
+
+    let set: HashSet<_> = [measure(1.0), measure(1.0), measure(-0.0)]
+        .into_iter()
+        .collect();
+    assert_eq!(set.len(), 2);
+}
+
+mod before {
+    #[derive(derive_bounded::HashEq)]
+    #[hash(named)]
+    pub struct Record {
+        pub id: u32,
+        pub name: String,
+        #[hash(unordered)]
+        #[partial_eq(unordered)]
+        pub tags: Vec<u8>,
+    }
+}
+
+mod after {
+    #[derive(derive_bounded::HashEq)]
+    #[hash(named)]
+    pub struct Record {
+        #[hash(unordered)]
+        #[partial_eq(unordered)]
+        pub tags: Vec<u8>,
+        pub name: String,
+        pub id: u32,
+    }
+}
+
+#[derive(HashEq)]
+#[hash(named)]
+struct NoFields {}
+
+#[test]
+fn hash_named() {
+    let hasher = std::collections::hash_map::RandomState::new();
+    let before = before::Record {
+        id: 1,
+        name: "one".to_owned(),
+        tags: vec![1, 2],
+    };
+    let after = after::Record {
+        tags: vec![2, 1],
+        name: "one".to_owned(),
+        id: 1,
+    };
+    let other = after::Record {
+        tags: vec![2, 1],
+        name: "1".to_owned(),
+        id: 1,
+    };
+
+    assert_eq!(hasher.hash_one(&before), hasher.hash_one(&after));
+    assert_ne!(hasher.hash_one(&after), hasher.hash_one(&other));
+    assert_eq!(hasher.hash_one(NoFields {}), hasher.hash_one(NoFields {}));
+}
+
+mod first {
+    #[derive(derive_bounded::HashEq)]
+    #[hash(variant_name)]
+    pub enum Event {
+        Start,
+        Data(u8),
+        Stop { code: i32 },
+    }
+}
+
+mod reordered {
+    #[derive(derive_bounded::HashEq)]
+    #[hash(variant_name)]
+    pub enum Event {
+        Stop { code: i32 },
+        Start,
+        Data(u8),
+    }
+}
+
+#[test]
+fn hash_variant_name() {
+    let hasher = std::collections::hash_map::RandomState::new();
+
+    assert_eq!(
+        hasher.hash_one(first::Event::Start),
+        hasher.hash_one(reordered::Event::Start)
+    );
+    assert_eq!(
+        hasher.hash_one(first::Event::Data(1)),
+        hasher.hash_one(reordered::Event::Data(1))
+    );
+    assert_eq!(
+        hasher.hash_one(first::Event::Stop { code: -1 }),
+        hasher.hash_one(reordered::Event::Stop { code: -1 })
+    );
+    assert_ne!(
+        hasher.hash_one(first::Event::Start),
+        hasher.hash_one(first::Event::Stop { code: 0 })
+    );
+}
@@ -0,0 +1,158 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, Default, Eq, PartialEq};
+
+trait Associate {
+    type A: PartialEq + Clone + std::fmt::Debug + Default + Eq;
+}
+
+#[derive(std::fmt::Debug)]
+struct Holder;
+
+impl Associate for Holder {
+    type A = u32;
+}
+
+macro_rules! large_struct {
+    ($name:ident { $($field:ident),* }) => {
+        #[derive(Clone, Debug, Default, PartialEq, Eq)]
+        #[bounded_to(T::A)]
+        struct $name<T: Associate> {
+            $($field: T::A,)*
+        }
+    };
+}
+
+macro_rules! large_enum {
+    ($name:ident { $($variant:ident),* }) => {
+        #[derive(Clone, Debug, PartialEq, Eq)]
+        #[bounded_to(T::A)]
+        enum $name<T: Associate> {
+            $($variant { a: T::A, b: T::A, c: u8 },)*
+        }
+    };
+}
+
+large_struct!(Large {
+    f00,
+    f01,
+    f02,
+    f03,
+    f04,
+    f05,
+    f06,
+    f07,
+    f08,
+    f09,
+    f10,
+    f11,
+    f12,
+    f13,
+    f14,
+    f15,
+    f16,
+    f17,
+    f18,
+    f19,
+    f20,
+    f21,
+    f22,
+    f23,
+    f24,
+    f25,
+    f26,
+    f27,
+    f28,
+    f29,
+    f30,
+    f31,
+    f32,
+    f33,
+    f34,
+    f35,
+    f36,
+    f37,
+    f38,
+    f39,
+    f40,
+    f41,
+    f42,
+    f43,
+    f44,
+    f45,
+    f46,
+    f47,
+    f48,
+    f49,
+    f50,
+    f51,
+    f52,
+    f53,
+    f54,
+    f55,
+    f56,
+    f57,
+    f58,
+    f59,
+    f60,
+    f61,
+    f62,
+    f63
+});
+
+large_enum!(LargeEnum {
+    V00,
+    V01,
+    V02,
+    V03,
+    V04,
+    V05,
+    V06,
+    V07,
+    V08,
+    V09,
+    V10,
+    V11,
+    V12,
+    V13,
+    V14,
+    V15,
+    V16,
+    V17,
+    V18,
+    V19,
+    V20,
+    V21,
+    V22,
+    V23,
+    V24,
+    V25,
+    V26,
+    V27,
+    V28,
+    V29,
+    V30,
+    V31
+});
+
+#[test]
+fn large_struct() {
+    let a = Large::<Holder> {
+        f63: 42,
+        ..Default::default()
+    };
+
+    let b = a.clone();
+
+    assert_eq!(a, b);
+    assert!(format!("{:?}", b).contains("f63: 42"));
+}
+
+#[test]
+fn large_enum() {
+    let a = LargeEnum::<Holder>::V31 { a: 1, b: 2, c: 3 };
+    let b = LargeEnum::<Holder>::V00 { a: 1, b: 2, c: 3 };
+
+    assert_eq!(a, a.clone());
+    assert_ne!(a, b);
+    assert_eq!(format!("{:?}", a), "V31 { a: 1, b: 2, c: 3 }");
+}
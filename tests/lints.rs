@@ -0,0 +1,64 @@
+#![deny(unused_qualifications)]
+#![allow(dead_code)]
+use derive_bounded::{Debug, FromStr, HashEq};
+
+trait Associate {
+    type A: Clone + std::fmt::Debug + Default + Ord + std::hash::Hash;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+#[derive(
+    derive_bounded::Clone,
+    Debug,
+    derive_bounded::Default,
+    HashEq,
+    derive_bounded::PartialOrd,
+    derive_bounded::Ord,
+)]
+#[bounded_to(T::A)]
+struct Named<T: Associate> {
+    a: T::A,
+    v: Vec<T::A>,
+}
+
+#[derive(
+    derive_bounded::Clone,
+    Debug,
+    derive_bounded::Default,
+    HashEq,
+    derive_bounded::PartialOrd,
+    derive_bounded::Ord,
+)]
+#[bounded_to(T::A)]
+struct Unnamed<T: Associate>(T::A, Option<T::A>);
+
+#[derive(
+    derive_bounded::Clone,
+    Debug,
+    derive_bounded::Default,
+    HashEq,
+    derive_bounded::PartialOrd,
+    derive_bounded::Ord,
+    FromStr,
+)]
+enum Kind {
+    #[default]
+    A,
+    B,
+}
+
+#[test]
+fn unused_qualifications() {
+    let n = Named::<Holder>::default();
+    assert_eq!(n.clone(), n);
+    assert_eq!(
+        Unnamed::<Holder>(1, None).cmp(&Unnamed(1, Some(0))),
+        std::cmp::Ordering::Less
+    );
+    assert_eq!("B".parse::<Kind>(), Ok(Kind::B));
+}
@@ -0,0 +1,54 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, Default, HashEq, PartialOrd};
+
+trait Associate {
+    type A: Clone + Default + std::cmp::Eq + std::hash::Hash + PartialOrd + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+macro_rules! bounded_struct {
+    ($name:ident, $field:ident: $ty:ty) => {
+        #[derive(Clone, Debug, Default, HashEq, PartialOrd)]
+        #[bounded_to(T::A)]
+        struct $name<T: Associate> {
+            $field: $ty,
+            fixed: T::A,
+        }
+    };
+}
+
+macro_rules! bounded_enum {
+    ($name:ident { $($variant:ident($ty:ty)),* }) => {
+        #[derive(Clone, Debug, HashEq, PartialOrd)]
+        #[bounded_to(T::A)]
+        enum $name<T: Associate> {
+            $($variant($ty),)*
+            Named { value: T::A },
+        }
+    };
+}
+
+bounded_struct!(Generated, value: T::A);
+bounded_enum!(GeneratedEnum { First(T::A), Second(u8) });
+
+#[test]
+fn macro_generated() {
+    let g = Generated::<Holder> { value: 1, fixed: 2 };
+
+    assert_eq!(g.clone(), g);
+    assert!(Generated::<Holder>::default() < g);
+    assert_eq!(format!("{:?}", g), "Generated { value: 1, fixed: 2 }");
+
+    let e = GeneratedEnum::<Holder>::First(1);
+    assert_eq!(e.clone(), e);
+    assert!(e < GeneratedEnum::Second(0));
+    assert_eq!(
+        format!("{:?}", GeneratedEnum::<Holder>::Named { value: 3 }),
+        "Named { value: 3 }"
+    );
+}
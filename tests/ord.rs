@@ -0,0 +1,189 @@
+#![allow(dead_code)]
+use std::cmp::Ordering;
+
+use derive_bounded::{Debug, Eq, Ord, PartialEq, PartialOrd};
+
+trait Associate {
+    type Key: Ord + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type Key = u8;
+}
+
+fn case_insensitive(a: &str, b: &str) -> Ordering {
+    a.to_lowercase().cmp(&b.to_lowercase())
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bounded_to(T::Key)]
+struct Entry<T: Associate> {
+    #[ord(with = "case_insensitive")]
+    name: String,
+    rank: T::Key,
+}
+
+#[test]
+fn ord_with() {
+    let mut entries = [
+        Entry::<Holder> {
+            name: "b".into(),
+            rank: 1,
+        },
+        Entry {
+            name: "A".into(),
+            rank: 2,
+        },
+        Entry {
+            name: "a".into(),
+            rank: 1,
+        },
+    ];
+    entries.sort();
+
+    let names: Vec<_> = entries.iter().map(|e| (e.name.as_str(), e.rank)).collect();
+    assert_eq!(names, [("a", 1), ("A", 2), ("b", 1)]);
+    assert_eq!(entries[0].partial_cmp(&entries[1]), Some(Ordering::Less));
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+enum Level {
+    High = 8,
+    Low = 1,
+    Named { depth: u8 } = 4,
+    Tuple(u8) = 0,
+}
+
+#[test]
+fn variant_declaration_order() {
+    let mut levels = [
+        Level::Tuple(1),
+        Level::Named { depth: 2 },
+        Level::Low,
+        Level::Named { depth: 1 },
+        Level::High,
+        Level::Tuple(0),
+    ];
+    levels.sort();
+
+    assert_eq!(
+        levels,
+        [
+            Level::High,
+            Level::Low,
+            Level::Named { depth: 1 },
+            Level::Named { depth: 2 },
+            Level::Tuple(0),
+            Level::Tuple(1),
+        ]
+    );
+    assert!(Level::High < Level::Low);
+    assert_eq!(
+        Level::Low.partial_cmp(&Level::High),
+        Some(Ordering::Greater)
+    );
+}
+
+/// Comparable for equality only
+#[derive(std::fmt::Debug, std::cmp::PartialEq, std::cmp::Eq)]
+struct Blob(Vec<u8>);
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bounded(partial_ord_only_fields(name, id))]
+struct Record<U: std::cmp::Eq> {
+    id: u32,
+    weight: f64,
+    name: &'static str,
+    payload: U,
+}
+
+#[test]
+fn ord_only_fields() {
+    let record = |id, name| Record {
+        id,
+        weight: id as f64 * 0.5,
+        name,
+        payload: Blob(vec![id as u8]),
+    };
+
+    let mut records = [record(2, "b"), record(3, "a"), record(1, "b")];
+    records.sort();
+    let order: Vec<_> = records.iter().map(|r| r.id).collect();
+    assert_eq!(order, [3, 1, 2]);
+
+    let mut other = record(3, "a");
+    other.weight = f64::NAN;
+    assert_eq!(records[0].partial_cmp(&other), Some(Ordering::Equal));
+}
+
+trait Tree {
+    type Key: Ord + std::fmt::Debug;
+}
+
+/// Not comparable, only its key is
+struct Forest;
+
+impl Tree for Forest {
+    type Key = &'static str;
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bounded_to(T::Key)]
+struct Node<T: Tree> {
+    key: T::Key,
+    children: Vec<Node<T>>,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[bounded_to(T::Key)]
+enum Branch<T: Tree> {
+    Leaf(T::Key),
+    Inner { key: T::Key, node: Node<T> },
+}
+
+#[test]
+fn recursive_associated_keys() {
+    let node = |key, children| Node::<Forest> { key, children };
+
+    let mut nodes = [
+        node("b", vec![]),
+        node("a", vec![node("c", vec![])]),
+        node("a", vec![node("b", vec![])]),
+        node("a", vec![]),
+    ];
+    nodes.sort();
+
+    let keys: Vec<_> = nodes
+        .iter()
+        .map(|n| (n.key, n.children.first().map(|c| c.key)))
+        .collect();
+    assert_eq!(
+        keys,
+        [("a", None), ("a", Some("b")), ("a", Some("c")), ("b", None)]
+    );
+
+    let mut branches = [
+        Branch::Inner {
+            key: "a",
+            node: node("z", vec![]),
+        },
+        Branch::Leaf("b"),
+        Branch::Inner {
+            key: "a",
+            node: node("y", vec![]),
+        },
+        Branch::<Forest>::Leaf("a"),
+    ];
+    branches.sort();
+
+    assert_eq!(branches[0], Branch::Leaf("a"));
+    assert_eq!(branches[1], Branch::Leaf("b"));
+    assert!(matches!(&branches[2], Branch::Inner { node, .. } if node.key == "y"));
+    assert_eq!(
+        branches[3].partial_cmp(&branches[2]),
+        Some(Ordering::Greater)
+    );
+}
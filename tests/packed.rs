@@ -0,0 +1,71 @@
+//! Packed structs, the fields are copied instead of borrowed as they may be unaligned.
+//!
+//! The miri CI job runs them with `cargo miri test --test packed` to check that no unaligned
+//! reference is created.
+use std::collections::HashSet;
+
+use derive_bounded::{Clone, Debug, HashEq, Ord, PartialOrd};
+
+trait Associate {
+    type A: Copy + std::cmp::Eq + std::hash::Hash + std::cmp::Ord + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u64;
+}
+
+#[derive(Clone, Debug, HashEq, PartialOrd, Ord)]
+#[bounded_to(T::A)]
+#[repr(C, packed)]
+struct Packed<T: Associate> {
+    tag: u8,
+    a: T::A,
+}
+
+#[derive(Clone, Debug, HashEq, PartialOrd, Ord)]
+#[bounded_to(T::A)]
+#[repr(C, packed(1))]
+struct PackedTuple<T: Associate>(u8, T::A);
+
+#[test]
+fn packed_named() {
+    let p = Packed::<Holder> { tag: 1, a: 2 };
+    let mut q = Packed::<Holder> { tag: 0, a: 0 };
+    q.clone_from(&p);
+
+    assert!(q == p);
+    assert!(Packed::<Holder> { tag: 1, a: 1 } < p);
+    assert_eq!(format!("{:?}", p), "Packed { tag: 1, a: 2 }");
+
+    let set: HashSet<_> = [p.clone(), q].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn packed_unnamed() {
+    let p = PackedTuple::<Holder>(1, 2);
+
+    assert!(p.clone() == p);
+    assert!(PackedTuple::<Holder>(0, 3) < p);
+    assert_eq!(format!("{:?}", p), "PackedTuple(1, 2)");
+
+    let set: HashSet<_> = [p.clone(), p].into_iter().collect();
+    assert_eq!(set.len(), 1);
+}
+
+#[derive(Clone, Debug, HashEq)]
+#[repr(C, packed)]
+struct PackedGeneric<T> {
+    tag: u8,
+    t: T,
+}
+
+#[test]
+fn packed_generic() {
+    let p = PackedGeneric { tag: 1, t: 2u32 };
+
+    assert!(p.clone() == p);
+    assert_eq!(format!("{:?}", p), "PackedGeneric { tag: 1, t: 2 }");
+}
@@ -34,6 +34,7 @@ struct A<T: Associate> {
     b: B<T>,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, PartialEq, Eq)]
 #[bounded_to(T::B, T::C, <T::D as Associate>::A)]
 enum En<T: Associate> {
@@ -53,6 +54,7 @@ struct C<T: Associate> {
     c: T::C,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, PartialEq, Debug, Eq)]
 #[bounded_to(T::C)]
 struct C2<T, V, Blah: Default>
@@ -64,13 +66,16 @@ where
     b: Blah,
 }
 
+#[allow(dead_code)]
 #[derive(Clone, PartialEq, Debug, Default, Eq)]
 #[bounded_to(T::C, T::B)]
 struct D<T: Associate>(T::C, T::B);
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Default, Eq)]
 struct ABase<A>(A);
 
+#[allow(dead_code)]
 #[derive(Clone, Debug, PartialEq, Default, Eq)]
 struct BBase<A> {
     a: A,
@@ -89,3 +94,145 @@ fn partial_eq() {
 
     assert_eq!(c, a.b.c);
 }
+
+trait Measure {
+    type Value: Copy;
+}
+
+struct Metric;
+
+impl Measure for Metric {
+    type Value = f64;
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct Sample<T: Measure> {
+    #[partial_eq(total_float)]
+    value: f64,
+    #[partial_eq(total_float)]
+    single: f32,
+    unit: u8,
+    marker: std::marker::PhantomData<T>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Reading {
+    Value(#[partial_eq(total_float)] f64),
+}
+
+#[test]
+fn total_float() {
+    let nan = Sample::<Metric> {
+        value: f64::NAN,
+        single: f32::NAN,
+        unit: 1,
+        marker: std::marker::PhantomData,
+    };
+
+    assert_eq!(nan, nan.clone());
+    assert_ne!(
+        nan,
+        Sample {
+            value: 1.0,
+            ..nan.clone()
+        }
+    );
+    assert_eq!(Reading::Value(f64::NAN), Reading::Value(f64::NAN));
+    assert_ne!(Reading::Value(0.0), Reading::Value(-0.0));
+}
+
+#[derive(PartialEq)]
+#[bounded_to(T::A)]
+#[partial_eq(with = "[T::A], Vec<T::A>")]
+struct Bytes<T: Associate>(Vec<T::A>);
+
+#[derive(PartialEq)]
+#[partial_eq(with = "str")]
+struct Name {
+    name: String,
+}
+
+#[test]
+fn partial_eq_with() {
+    let bytes = Bytes::<Holder>(vec![1, 2]);
+
+    assert!(bytes == [1, 2][..]);
+    assert!(bytes != [1][..]);
+    assert!(bytes == vec![1, 2]);
+    assert!(bytes == Bytes(vec![1, 2]));
+
+    let name = Name {
+        name: "a".to_owned(),
+    };
+    assert!(name == *"a");
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+#[bounded_to(T::C)]
+enum Wrapped<T: Associate> {
+    Wrap(C<T>),
+    Pair { left: C<T>, right: T::C },
+    Empty,
+}
+
+fn assert_eq_impl<E: std::cmp::Eq>(_: &E) {}
+
+#[test]
+fn enum_wrapping_bounded_struct() {
+    // `Holder` implements neither PartialEq nor Eq, `C<Holder>` does through `T::C`
+    let w = Wrapped::<Holder>::Wrap(C { c: 1 });
+    assert_eq_impl(&w);
+    assert_eq!(w.clone(), w);
+    assert_ne!(w, Wrapped::Wrap(C { c: 2 }));
+    assert_ne!(w, Wrapped::Empty);
+
+    let p = Wrapped::<Holder>::Pair {
+        left: C { c: 1 },
+        right: 2,
+    };
+    assert_eq!(p.clone(), p);
+    assert_ne!(
+        p,
+        Wrapped::Pair {
+            left: C { c: 2 },
+            right: 2
+        }
+    );
+}
+
+#[derive(Debug, PartialEq)]
+#[partial_eq(approx)]
+#[bounded_to(T::A)]
+struct Position<T: Associate> {
+    x: f64,
+    y: f32,
+    id: T::A,
+}
+
+#[derive(PartialEq)]
+#[partial_eq(approx)]
+struct Span(f64, f64);
+
+#[test]
+fn approx_eq() {
+    let a = Position::<Holder> {
+        x: 1.0,
+        y: 2.0,
+        id: 1,
+    };
+    let b = Position::<Holder> {
+        x: 1.05,
+        y: 1.9,
+        id: 1,
+    };
+
+    assert!(a != b);
+    assert!(!a.approx_eq(&b, 0.0));
+    assert!(!a.approx_eq(&b, 0.06));
+    assert!(a.approx_eq(&b, 0.11));
+    assert!(!a.approx_eq(&Position { id: 2, ..b }, 1.0));
+
+    let infinite = Span(f64::INFINITY, 0.0);
+    assert!(infinite.approx_eq(&Span(f64::INFINITY, 0.5), 0.5));
+    assert!(!Span(f64::NAN, 0.0).approx_eq(&Span(f64::NAN, 0.0), f64::INFINITY));
+}
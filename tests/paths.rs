@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+#[cfg(test)]
+mod nested {
+    #![no_implicit_prelude]
+
+    use ::derive_bounded::{Clone, Debug, Default, HashEq, Ord, PartialOrd};
+
+    /// Shadows the standard library for the relative paths written in this module
+    mod std {}
+
+    trait Associate {
+        type A: ::std::clone::Clone
+            + ::std::fmt::Debug
+            + ::std::default::Default
+            + ::std::cmp::Ord
+            + ::std::hash::Hash;
+    }
+
+    struct Holder;
+
+    impl Associate for Holder {
+        type A = u8;
+    }
+
+    #[derive(Clone, Debug, Default, HashEq, PartialOrd, Ord)]
+    #[cfg_attr(feature = "wrapper", derive(::derive_bounded::Wrapper))]
+    #[bounded_to(T::A)]
+    struct Single<T: Associate> {
+        a: T::A,
+    }
+
+    #[derive(Clone, Debug, Default, HashEq, PartialOrd, Ord)]
+    #[bounded_to(T::A)]
+    enum Choice<T: Associate> {
+        #[default]
+        None,
+        Some(T::A),
+    }
+
+    #[::derive_bounded::bounded(derive(Clone, Debug), to(T::A))]
+    struct Umbrella<T: Associate>(T::A);
+
+    #[derive(Debug)]
+    #[debug(with_discriminant)]
+    enum Color {
+        Red = 1,
+        Green,
+    }
+
+    #[derive(Debug)]
+    struct Listed {
+        #[debug(max_len = 1)]
+        items: ::std::vec::Vec<u8>,
+    }
+
+    #[derive(Debug)]
+    struct Skipped(
+        u8,
+        #[debug(skip_if = "::std::option::Option::is_none")] ::std::option::Option<u8>,
+    );
+
+    #[derive(Debug)]
+    #[bounded(debug_pretty)]
+    struct Pretty(u8);
+
+    #[derive(::derive_bounded::PartialEq, ::derive_bounded::Hash)]
+    struct Bag {
+        #[partial_eq(unordered)]
+        #[hash(unordered)]
+        items: ::std::vec::Vec<u8>,
+    }
+
+    #[derive(::derive_bounded::Display)]
+    #[display(join = ", ")]
+    struct Pair(u8, u8);
+
+    #[test]
+    fn absolute_paths() {
+        let single = Single::<Holder> { a: 1 };
+        ::std::assert!(::std::clone::Clone::clone(&single) == single);
+        ::std::assert_eq!(::std::format!("{:?}", single), "Single { a: 1 }");
+        #[cfg(feature = "wrapper")]
+        ::std::assert_eq!(single.into_inner(), 1);
+
+        let choice = Choice::<Holder>::Some(1);
+        ::std::assert!(choice > ::std::default::Default::default());
+        ::std::assert_eq!(
+            ::std::format!("{:?}", ::std::clone::Clone::clone(&Umbrella::<Holder>(2))),
+            "Umbrella(2)"
+        );
+    }
+
+    #[test]
+    fn absolute_debug_paths() {
+        ::std::assert_eq!(::std::format!("{:?}", Color::Green), "Green (2)");
+        ::std::assert_eq!(
+            ::std::format!(
+                "{:?}",
+                Listed {
+                    items: ::std::vec![1, 2]
+                }
+            ),
+            "Listed { items: [1, ..] }"
+        );
+        ::std::assert_eq!(
+            ::std::format!("{:?}", Skipped(1, ::std::option::Option::None)),
+            "Skipped(1, ..)"
+        );
+        ::std::assert_eq!(::std::format!("{:?}", Pretty(1)), "Pretty(\n    1,\n)");
+    }
+
+    #[test]
+    fn absolute_iterator_paths() {
+        let bag = |items| Bag { items };
+        ::std::assert!(bag(::std::vec![1, 2]) == bag(::std::vec![2, 1]));
+        ::std::assert!(bag(::std::vec![1, 1]) != bag(::std::vec![1, 2]));
+
+        let state = ::std::collections::hash_map::RandomState::new();
+        ::std::assert_eq!(
+            ::std::hash::BuildHasher::hash_one(&state, bag(::std::vec![1, 2])),
+            ::std::hash::BuildHasher::hash_one(&state, bag(::std::vec![2, 1]))
+        );
+        ::std::assert_eq!(::std::format!("{}", Pair(1, 2)), "1, 2");
+    }
+}
@@ -0,0 +1,145 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, Default, Eq, PartialEq};
+
+trait Outer {
+    type X: Inner;
+}
+
+trait Inner {
+    type Y;
+}
+
+struct Source;
+
+struct Middle;
+
+impl Outer for Source {
+    type X = Middle;
+}
+
+impl Inner for Middle {
+    type Y = u32;
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[bounded_to(<<T as Outer>::X as Inner>::Y)]
+struct Deep<T: Outer> {
+    y: <<T as Outer>::X as Inner>::Y,
+    v: Vec<<<T as Outer>::X as Inner>::Y>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+#[bounded_to(<<T as Outer>::X as Inner>::Y)]
+enum DeepEnum<T: Outer> {
+    #[default]
+    None,
+    Some(<<T as Outer>::X as Inner>::Y),
+}
+
+fn assert_eq<E: std::cmp::Eq>(_: &E) {}
+
+#[test]
+fn two_level_qualified() {
+    let d = Deep::<Source> {
+        y: 1,
+        v: vec![2, 3],
+    };
+
+    assert_eq(&d);
+    assert_eq!(d, d.clone());
+    assert_eq!(format!("{:?}", d), "Deep { y: 1, v: [2, 3] }");
+    assert_eq!(Deep::<Source>::default().v, vec![]);
+
+    let e = DeepEnum::<Source>::Some(4);
+    assert_eq(&e);
+    assert_eq!(e.clone(), e);
+    assert_eq!(DeepEnum::<Source>::default(), DeepEnum::None);
+    assert_eq!(format!("{:?}", e), "Some(4)");
+}
+
+trait Assoc {
+    type A;
+}
+
+impl Assoc for Source {
+    type A = String;
+}
+
+#[derive(Clone, Debug, PartialEq)]
+#[bounded_to(U)]
+struct Equated<T, U>
+where
+    T: Assoc<A = U>,
+{
+    a: T::A,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+enum Inferred<T: Assoc<A = U>, U> {
+    A(T::A),
+    U(U),
+}
+
+#[test]
+fn associated_type_equality() {
+    let e = Equated::<Source, String> { a: "a".into() };
+    assert_eq!(e.clone(), e);
+
+    let i = Inferred::<Source, String>::A("a".into());
+    assert_eq!(i.clone(), i);
+    assert_ne!(i, Inferred::U("a".into()));
+}
+
+/// Hides the associated type from the inference
+type Alias<T> = <T as Assoc>::A;
+
+#[derive(Clone, Debug, PartialEq)]
+#[bounded_to(Alias<T>)]
+struct Aliased<T: Assoc> {
+    a: Alias<T>,
+    v: Vec<Alias<T>>,
+}
+
+#[test]
+fn alias_in_bounded_to() {
+    // `Source` implements neither Clone nor PartialEq
+    let a = Aliased::<Source> {
+        a: "a".into(),
+        v: vec!["b".into()],
+    };
+    assert_eq!(a.clone(), a);
+    assert_eq!(format!("{:?}", a), r#"Aliased { a: "a", v: ["b"] }"#);
+}
+
+/// Second trait bounding the parameter next to `Assoc`
+trait Named {
+    fn name() -> &'static str;
+}
+
+impl Named for Source {
+    fn name() -> &'static str {
+        "source"
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[bounded_to(<T as Assoc>::A)]
+struct Both<T>
+where
+    T: Assoc + Named,
+{
+    a: <T as Assoc>::A,
+}
+
+impl<T: Assoc + Named> Both<T> {
+    fn name(&self) -> &'static str {
+        T::name()
+    }
+}
+
+#[test]
+fn multiple_trait_bounds() {
+    let b = Both::<Source> { a: "a".into() };
+    assert_eq!(b.clone(), b);
+    assert_eq!(b.clone().name(), "source");
+}
@@ -0,0 +1,41 @@
+trait Associate {
+    type A;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+/// Implements none of the derived traits
+struct Opaque;
+
+impl Associate for Opaque {
+    type A = u8;
+}
+
+#[derive(derive_bounded::Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+#[derive_bounded::bounded_to(T::A)]
+struct After<T: Associate> {
+    a: T::A,
+}
+
+#[derive_bounded::bounded_to(T::A)]
+#[derive(derive_bounded::Clone, derive_bounded::Debug, derive_bounded::PartialEq)]
+enum Before<T: Associate> {
+    A(T::A),
+    Empty,
+}
+
+#[test]
+fn qualified_attr() {
+    let a = After::<Opaque> { a: 1 };
+    assert!(a.clone() == a);
+    assert_eq!(format!("{:?}", a), "After { a: 1 }");
+
+    let b = Before::<Opaque>::A(2);
+    assert!(b.clone() == b);
+    assert!(Before::<Holder>::Empty != Before::A(2));
+    assert_eq!(format!("{:?}", b), "A(2)");
+}
@@ -0,0 +1,73 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, Default, HashEq};
+use serde::{Deserialize, Serialize};
+
+trait Associate {
+    type A: std::clone::Clone + std::fmt::Debug + std::default::Default + Eq + std::hash::Hash;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u32;
+}
+
+#[derive(Clone, Debug, Default, HashEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+#[bounded_to(T::A)]
+#[serde(bound = "T::A: Serialize + serde::de::DeserializeOwned")]
+struct Config<T: Associate> {
+    #[serde(rename = "id")]
+    user_id: T::A,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    nick_name: Option<String>,
+    #[serde(skip)]
+    #[debug(skip_if = "Vec::is_empty")]
+    cache: Vec<u8>,
+}
+
+#[derive(Clone, Debug, HashEq, Serialize, Deserialize)]
+#[serde(tag = "kind")]
+enum Event {
+    #[serde(rename = "start")]
+    Start,
+    Data {
+        #[serde(alias = "value")]
+        payload: u8,
+    },
+}
+
+#[test]
+fn serde_attributes() {
+    let c = Config::<Holder> {
+        user_id: 1,
+        nick_name: None,
+        cache: Vec::new(),
+    };
+
+    assert_eq!(c.clone(), c);
+    assert_eq!(format!("{:?}", c), "Config { user_id: 1, nick_name: None }");
+    assert_eq!(Config::<Holder>::default().user_id, 0);
+
+    let e = Event::Data { payload: 1 };
+    assert_eq!(e.clone(), e);
+    assert_eq!(format!("{:?}", Event::Start), "Start");
+}
+
+/// Stands for a framework re-exporting serde
+mod framework {
+    pub use serde;
+}
+
+#[derive(Clone, Debug)]
+#[bounded_to(T::A, T::A: ::serde::Serialize)]
+#[bounded(crate_paths(serde = "crate::framework::serde"))]
+struct Exported<T: Associate> {
+    a: T::A,
+}
+
+#[test]
+fn crate_paths() {
+    let e = Exported::<Holder> { a: 1 };
+    assert_eq!(format!("{:?}", e.clone()), "Exported { a: 1 }");
+}
@@ -0,0 +1,103 @@
+//! Every shape with every derive, exercising the impls on a value
+
+#![allow(dead_code)]
+
+use std::cmp::Ordering;
+use std::collections::hash_map::RandomState;
+use std::hash::BuildHasher;
+
+trait Associate {
+    type A: Clone + std::fmt::Debug + Ord + std::hash::Hash;
+}
+
+/// Implements none of the derived traits
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+macro_rules! smoke {
+    ($($test:ident: $ty:ty = $value:expr => $debug:literal, $item:item)*) => {
+        $(
+            #[test]
+            fn $test() {
+                #[derive(
+                    derive_bounded::CloneBounded,
+                    derive_bounded::DebugBounded,
+                    derive_bounded::PartialEqBounded,
+                    derive_bounded::EqBounded,
+                    derive_bounded::PartialOrdBounded,
+                    derive_bounded::OrdBounded,
+                    derive_bounded::HashBounded
+                )]
+                $item
+
+                let value: $ty = $value;
+                let clone = value.clone();
+                assert_eq!(format!("{:?}", value), $debug);
+                assert!(clone == value);
+                assert_eq!(clone.partial_cmp(&value), Some(Ordering::Equal));
+                assert_eq!(clone.cmp(&value), Ordering::Equal);
+
+                let state = RandomState::new();
+                assert_eq!(state.hash_one(&clone), state.hash_one(&value));
+
+                let mut target = clone.clone();
+                target.clone_from(&value);
+                assert!(target == value);
+            }
+        )*
+    };
+}
+
+smoke! {
+    named_struct: Shape<Holder> = Shape { a: 1, b: 2 } => "Shape { a: 1, b: 2 }",
+    #[bounded_to(T::A)]
+    struct Shape<T: Associate> {
+        a: T::A,
+        b: u8,
+    }
+
+    tuple_struct: Shape<Holder> = Shape(1, 2) => "Shape(1, 2)",
+    #[bounded_to(T::A)]
+    struct Shape<T: Associate>(T::A, u8);
+
+    unit_struct: Shape = Shape => "Shape",
+    struct Shape;
+
+    empty_named_struct: Shape = Shape {} => "Shape",
+    struct Shape {}
+
+    empty_tuple_struct: Shape = Shape() => "Shape",
+    struct Shape();
+
+    unit_variant: Shape<Holder> = Shape::Unit => "Unit",
+    #[bounded_to(T::A)]
+    enum Shape<T: Associate> {
+        Unit,
+        Tuple(T::A),
+        Named { a: T::A },
+    }
+
+    tuple_variant: Shape<Holder> = Shape::Tuple(1) => "Tuple(1)",
+    #[bounded_to(T::A)]
+    enum Shape<T: Associate> {
+        Unit,
+        Tuple(T::A),
+        Named { a: T::A },
+    }
+
+    named_variant: Shape<Holder> = Shape::Named { a: 1 } => "Named { a: 1 }",
+    #[bounded_to(T::A)]
+    enum Shape<T: Associate> {
+        Unit,
+        Tuple(T::A),
+        Named { a: T::A },
+    }
+
+    single_variant: Shape = Shape::Only => "Only",
+    enum Shape {
+        Only,
+    }
+}
@@ -0,0 +1,38 @@
+use derive_bounded::{Clone, Debug, PartialEq};
+
+trait Associate {
+    type A;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = u8;
+}
+
+/// Implements none of the derived traits
+struct Opaque;
+
+#[derive(Clone, Debug, PartialEq)]
+#[bounded(strict)]
+#[bounded_to(T::A, U)]
+struct Strict<T: Associate, U, V> {
+    a: T::A,
+    u: Vec<U>,
+    v: std::marker::PhantomData<V>,
+}
+
+#[test]
+fn strict() {
+    let s = Strict::<Holder, u16, Opaque> {
+        a: 1,
+        u: vec![2],
+        v: std::marker::PhantomData,
+    };
+
+    assert!(s.clone() == s);
+    assert_eq!(
+        format!("{:?}", s),
+        "Strict { a: 1, u: [2], v: PhantomData<strict::Opaque> }"
+    );
+}
@@ -0,0 +1,80 @@
+#![allow(dead_code)]
+use std::cmp::Ordering;
+use std::collections::{BTreeSet, HashSet};
+
+use derive_bounded::{Debug, TotalOrd};
+
+trait Associate {
+    type Key: Ord + std::hash::Hash + std::fmt::Debug;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type Key = u8;
+}
+
+#[derive(Debug, TotalOrd)]
+#[bounded_to(T::Key)]
+#[bounded(partial_ord_only_fields(rank, name))]
+struct Player<T: Associate> {
+    name: &'static str,
+    rank: T::Key,
+    // Not compared nor hashed
+    seen: u32,
+}
+
+#[derive(Debug, TotalOrd)]
+#[bounded_to(T::Key)]
+enum Slot<T: Associate> {
+    Empty,
+    Taken(T::Key),
+}
+
+fn player(name: &'static str, rank: u8, seen: u32) -> Player<Holder> {
+    Player { name, rank, seen }
+}
+
+#[test]
+fn total_ord() {
+    let mut players = [player("b", 2, 0), player("a", 2, 1), player("c", 1, 2)];
+    players.sort();
+    let names: Vec<_> = players.iter().map(|p| p.name).collect();
+    assert_eq!(names, ["c", "a", "b"]);
+
+    assert_eq!(player("a", 1, 0), player("a", 1, 5));
+    assert_ne!(player("a", 1, 0), player("b", 1, 0));
+
+    let set: HashSet<_> = [player("a", 1, 0), player("a", 1, 5)].into_iter().collect();
+    assert_eq!(set.len(), 1);
+
+    let slots: BTreeSet<_> = [Slot::<Holder>::Taken(2), Slot::Empty, Slot::Taken(1)]
+        .into_iter()
+        .collect();
+    assert_eq!(format!("{:?}", slots), "{Empty, Taken(1), Taken(2)}");
+    assert!(Slot::<Holder>::Taken(1) == Slot::Taken(1));
+}
+
+#[test]
+fn eq_matches_cmp() {
+    let players = [
+        player("a", 1, 0),
+        player("a", 1, 5),
+        player("a", 2, 0),
+        player("b", 1, 0),
+    ];
+    for a in &players {
+        for b in &players {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{:?} {:?}", a, b);
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+        }
+    }
+
+    let slots = [Slot::<Holder>::Empty, Slot::Taken(1), Slot::Taken(2)];
+    for a in &slots {
+        for b in &slots {
+            assert_eq!(a == b, a.cmp(b) == Ordering::Equal, "{:?} {:?}", a, b);
+            assert_eq!(a.partial_cmp(b), Some(a.cmp(b)));
+        }
+    }
+}
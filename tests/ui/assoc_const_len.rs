@@ -0,0 +1,12 @@
+use derive_bounded::Clone;
+
+trait Layout {
+    const LEN: usize;
+}
+
+#[derive(Clone)]
+struct Buffer<T: Layout> {
+    data: [u8; T::LEN],
+}
+
+fn main() {}
@@ -0,0 +1,19 @@
+use derive_bounded::Clone;
+
+trait Associate {
+    type A: Clone;
+}
+
+#[derive(Clone)]
+#[bounded_to(())]
+struct Empty<T: Associate> {
+    a: T::A,
+}
+
+#[derive(Clone)]
+#[bounded_to(T::A, types())]
+struct EmptyList<T: Associate> {
+    a: T::A,
+}
+
+fn main() {}
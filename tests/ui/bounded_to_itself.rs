@@ -0,0 +1,19 @@
+use derive_bounded::Clone;
+
+trait Trait {
+    type Item;
+}
+
+#[derive(Clone)]
+#[bounded_to(T::Item, Self)]
+struct A<T: Trait> {
+    f: T::Item,
+}
+
+#[derive(Clone)]
+#[bounded_to(B<T>)]
+struct B<T: Trait> {
+    f: T::Item,
+}
+
+fn main() {}
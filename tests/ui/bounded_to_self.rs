@@ -0,0 +1,13 @@
+use derive_bounded::Clone;
+
+trait Trait {
+    type Item;
+}
+
+#[derive(Clone)]
+#[bounded_to(Self::Item)]
+struct A<T: Trait> {
+    f: T::Item,
+}
+
+fn main() {}
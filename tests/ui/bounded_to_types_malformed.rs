@@ -0,0 +1,15 @@
+use derive_bounded::Clone;
+
+trait Associate {
+    type A: Clone;
+    type B: Clone;
+}
+
+#[derive(Clone)]
+#[bounded_to(types(T::A T::B))]
+struct Listed<T: Associate> {
+    a: T::A,
+    b: T::B,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use derive_bounded::Clone;
+
+trait Associate {
+    type A;
+}
+
+#[derive(Clone)]
+struct Lazy<T: Associate> {
+    make: Box<dyn Fn() -> T::A>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use derive_bounded::Clone;
+
+trait Associate {
+    type A;
+}
+
+#[derive(Clone)]
+struct Source<T: Associate> {
+    iter: Box<dyn Iterator<Item = T::A>>,
+}
+
+fn main() {}
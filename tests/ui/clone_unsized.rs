@@ -0,0 +1,15 @@
+use derive_bounded::{Clone, Default};
+
+#[derive(Clone)]
+struct Packet {
+    header: u32,
+    body: [u8],
+}
+
+#[derive(Default)]
+struct Text {
+    len: usize,
+    body: str,
+}
+
+fn main() {}
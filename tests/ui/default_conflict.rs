@@ -0,0 +1,16 @@
+use derive_bounded::Default;
+
+fn zero() -> u8 {
+    0
+}
+
+#[derive(Default)]
+struct Conflict {
+    #[default = 1]
+    #[default(with = "zero")]
+    a: u8,
+    #[default(value = "1", with = "zero")]
+    b: u8,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_bounded::FromStr;
+
+#[derive(FromStr)]
+enum Shape {
+    Point,
+    Circle(u32),
+}
+
+fn main() {}
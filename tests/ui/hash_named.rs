@@ -0,0 +1,7 @@
+use derive_bounded::HashEq;
+
+#[derive(HashEq)]
+#[hash(named)]
+struct Pair(u8, u8);
+
+fn main() {}
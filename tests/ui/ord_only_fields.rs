@@ -0,0 +1,10 @@
+use derive_bounded::{PartialEq, PartialOrd};
+
+#[derive(PartialEq, PartialOrd)]
+#[bounded(partial_ord_only_fields(name, missing))]
+struct Record {
+    id: u32,
+    name: String,
+}
+
+fn main() {}
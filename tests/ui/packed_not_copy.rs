@@ -0,0 +1,10 @@
+use derive_bounded::Debug;
+
+#[derive(Debug)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use derive_bounded::PartialEq;
+
+#[derive(PartialEq)]
+#[partial_eq(approx)]
+enum Reading {
+    Value(f64),
+}
+
+fn main() {}
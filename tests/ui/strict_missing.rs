@@ -0,0 +1,15 @@
+use derive_bounded::Clone;
+
+trait Associate {
+    type A;
+}
+
+#[derive(Clone)]
+#[bounded(strict)]
+#[bounded_to(T::A)]
+struct Strict<T: Associate, U> {
+    a: T::A,
+    u: Vec<U>,
+}
+
+fn main() {}
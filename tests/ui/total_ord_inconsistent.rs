@@ -0,0 +1,18 @@
+use derive_bounded::TotalOrd;
+
+#[derive(TotalOrd)]
+#[partial_eq(approx)]
+struct Pair {
+    #[partial_eq(unordered)]
+    a: Vec<u8>,
+    #[ord(with = "std::cmp::Ord::cmp")]
+    b: u8,
+}
+
+#[derive(TotalOrd)]
+enum Either {
+    Left(#[hash(unordered)] Vec<u8>),
+    Right(#[partial_eq(total_float)] u8),
+}
+
+fn main() {}
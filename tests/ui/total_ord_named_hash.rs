@@ -0,0 +1,11 @@
+use derive_bounded::TotalOrd;
+
+#[derive(TotalOrd)]
+#[hash(named)]
+#[bounded(partial_ord_only_fields(a))]
+struct Pair {
+    a: u8,
+    b: u8,
+}
+
+fn main() {}
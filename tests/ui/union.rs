@@ -0,0 +1,9 @@
+use derive_bounded::Clone;
+
+#[derive(Clone)]
+union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,47 @@
+#![allow(dead_code)]
+use derive_bounded::bounded;
+
+trait Associate {
+    type A;
+    type B: Clone + std::fmt::Debug + PartialEq + Eq;
+    type C: Clone + std::fmt::Debug + PartialEq + Eq;
+}
+
+struct Holder;
+
+impl Associate for Holder {
+    type A = ();
+    type B = String;
+    type C = u8;
+}
+
+#[bounded(derive(Clone, Debug, PartialEq, Eq), to(T::B, T::C))]
+struct Pair<T: Associate> {
+    b: T::B,
+    c: T::C,
+}
+
+#[derive_bounded::bounded(derive(Clone, Eq, PartialEq), to(<T as Associate>::B))]
+#[bounded(eq_bound(T::B))]
+enum Either<T: Associate> {
+    B(T::B),
+    Nothing,
+}
+
+fn assert_eq<E: std::cmp::Eq>(_: &E) {}
+
+#[test]
+fn umbrella() {
+    let p = Pair::<Holder> {
+        b: "b".to_owned(),
+        c: 1,
+    };
+
+    assert_eq(&p);
+    assert_eq!(p.clone(), p);
+    assert_eq!(format!("{:?}", p), r#"Pair { b: "b", c: 1 }"#);
+
+    let e = Either::<Holder>::B("b".to_owned());
+    assert_eq(&e);
+    assert!(e.clone() == e);
+}
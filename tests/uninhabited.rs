@@ -0,0 +1,25 @@
+#![allow(dead_code)]
+use derive_bounded::{Clone, Debug, HashEq};
+
+#[derive(Clone, Debug, HashEq)]
+enum Never {}
+
+#[derive(Clone, Debug, HashEq)]
+struct Impossible<T> {
+    value: T,
+    never: Never,
+}
+
+#[derive(Clone, Debug, HashEq)]
+enum Either<T> {
+    Value(T),
+    Never(Never),
+}
+
+#[test]
+fn uninhabited_fields() {
+    let ok: Result<Either<u8>, Impossible<u8>> = Ok(Either::Value(1));
+
+    assert_eq!(ok.clone(), ok);
+    assert_eq!(format!("{:?}", ok), "Ok(Value(1))");
+}
@@ -0,0 +1,48 @@
+#![deny(unused)]
+use std::collections::HashSet;
+
+use derive_bounded::{Clone, Debug, Default, HashEq};
+
+trait Associate {
+    type A: std::cmp::Eq + std::hash::Hash + Clone + std::fmt::Debug + Default;
+}
+
+#[derive(std::fmt::Debug)]
+struct Holder;
+
+impl Associate for Holder {
+    type A = u32;
+}
+
+#[derive(Clone, Debug, Default, HashEq)]
+#[bounded_to(T::A)]
+enum Many<T: Associate> {
+    #[default]
+    Unit,
+    Empty(),
+    EmptyNamed {},
+    One(T::A),
+    Several(T::A, u8, T::A, u16),
+    Named {
+        a: T::A,
+        b: u8,
+        c: T::A,
+    },
+}
+
+#[test]
+fn no_unused_bindings() {
+    let values = [
+        Many::<Holder>::default(),
+        Many::Empty(),
+        Many::EmptyNamed {},
+        Many::One(1),
+        Many::Several(1, 2, 3, 4),
+        Many::Named { a: 1, b: 2, c: 3 },
+    ];
+
+    let set: HashSet<_> = values.iter().cloned().collect();
+    assert_eq!(set.len(), values.len());
+    assert!(values.iter().all(|v| set.contains(v)));
+    assert_eq!(format!("{:?}", values[1]), "Empty");
+}
@@ -0,0 +1,31 @@
+#![cfg(feature = "wrapper")]
+use derive_bounded::Wrapper;
+
+trait Associate {
+    type A;
+}
+
+struct NotClone;
+
+impl Associate for NotClone {
+    type A = Vec<u8>;
+}
+
+#[derive(Wrapper)]
+struct Tuple<T: Associate>(T::A);
+
+#[derive(Wrapper)]
+struct Named<'a, T> {
+    inner: &'a [T],
+}
+
+#[test]
+fn into_inner() {
+    let t = Tuple::<NotClone>(vec![1, 2]);
+    assert_eq!(t.as_inner(), &[1, 2]);
+    assert_eq!(t.into_inner(), vec![1, 2]);
+
+    let n = Named { inner: &[NotClone] };
+    assert_eq!(n.as_inner().len(), 1);
+    assert_eq!(n.into_inner().len(), 1);
+}